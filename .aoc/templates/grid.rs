@@ -0,0 +1,32 @@
+use advent_of_code::utils::grid::Grid;
+
+advent_of_code::solution!(YEAR_NUMBER, DAY_NUMBER, parse);
+
+pub fn parse(input: &str) -> Grid<char> {
+    input.parse().expect("input is a grid")
+}
+
+pub fn part_one(grid: &Grid<char>) -> Option<ANSWER_TYPE> {
+    None
+}
+
+pub fn part_two(grid: &Grid<char>) -> Option<ANSWER_TYPE> {
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let result = part_one(&parse(&advent_of_code::template::read_file("examples", YEAR, DAY)));
+        assert_eq!(result, PART_ONE_EXPECTED);
+    }
+
+    #[test]
+    fn test_part_two() {
+        let result = part_two(&parse(&advent_of_code::template::read_file("examples", YEAR, DAY)));
+        assert_eq!(result, PART_TWO_EXPECTED);
+    }
+}
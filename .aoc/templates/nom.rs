@@ -0,0 +1,39 @@
+use nom::character::complete::{line_ending, space1, u64 as u64_parser};
+use nom::multi::separated_list1;
+use nom::IResult;
+
+advent_of_code::solution!(YEAR_NUMBER, DAY_NUMBER, parse);
+
+pub fn parse(input: &str) -> Vec<Vec<u64>> {
+    let (_, lines) = parse_lines(input.trim_end()).expect("valid input");
+    lines
+}
+
+fn parse_lines(input: &str) -> IResult<&str, Vec<Vec<u64>>> {
+    separated_list1(line_ending, separated_list1(space1, u64_parser))(input)
+}
+
+pub fn part_one(lines: &[Vec<u64>]) -> Option<ANSWER_TYPE> {
+    None
+}
+
+pub fn part_two(lines: &[Vec<u64>]) -> Option<ANSWER_TYPE> {
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let result = part_one(&parse(&advent_of_code::template::read_file("examples", YEAR, DAY)));
+        assert_eq!(result, PART_ONE_EXPECTED);
+    }
+
+    #[test]
+    fn test_part_two() {
+        let result = part_two(&parse(&advent_of_code::template::read_file("examples", YEAR, DAY)));
+        assert_eq!(result, PART_TWO_EXPECTED);
+    }
+}
@@ -0,0 +1,56 @@
+//! Generates the solution registry: with the `registry` feature, every `src/bin/YYYY-DD.rs` is compiled into
+//! the library as a module, so solutions can be called in-process (see `template::registry`).
+//! Without it the registry is empty, and unfinished solutions can't break the other commands.
+use std::{env, fs, path::Path};
+
+fn main() {
+    println!("cargo:rerun-if-changed=src/bin");
+
+    let out_dir = env::var("OUT_DIR").unwrap();
+    let out_path = Path::new(&out_dir).join("solutions.rs");
+
+    if env::var_os("CARGO_FEATURE_REGISTRY").is_none() {
+        fs::write(out_path, "static SOLUTIONS: &[Solution] = &[];\n").unwrap();
+        return;
+    }
+
+    let manifest_dir = env::var("CARGO_MANIFEST_DIR").unwrap();
+    let bin_dir = Path::new(&manifest_dir).join("src").join("bin");
+
+    let mut solutions: Vec<(u16, u8, String)> = fs::read_dir(&bin_dir)
+        .map(|entries| {
+            entries
+                .filter_map(Result::ok)
+                .filter_map(|entry| {
+                    let name = entry.file_name().into_string().ok()?;
+                    let (year, day) = name.strip_suffix(".rs")?.split_once('-')?;
+                    if year.len() != 4 || day.len() != 2 {
+                        return None;
+                    }
+                    let year = year.parse::<u16>().ok()?;
+                    let day = day.parse::<u8>().ok().filter(|d| (1..=25).contains(d))?;
+                    Some((year, day, entry.path().to_string_lossy().into_owned()))
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+
+    solutions.sort_unstable();
+
+    let mut out = String::new();
+
+    for (year, day, path) in &solutions {
+        out.push_str(&format!(
+            "#[allow(dead_code, unused_imports)]\n#[path = {path:?}]\nmod y{year}_day{day:02};\n\n"
+        ));
+    }
+
+    out.push_str("/// Solutions found in `src/bin`, ordered by year and day.\n");
+    out.push_str("static SOLUTIONS: &[Solution] = &[\n");
+    for (year, day, _) in &solutions {
+        out.push_str(&format!("    y{year}_day{day:02}::SOLUTION,\n"));
+    }
+    out.push_str("];\n");
+
+    fs::write(out_path, out).unwrap();
+}
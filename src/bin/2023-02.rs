@@ -1,6 +1,6 @@
 use std::cmp::max;
 use std::str::FromStr;
-advent_of_code::solution!(2);
+advent_of_code::solution!(2023, 2);
 
 
 const RULES: [u32; 3] = [12, 13, 14];
@@ -107,13 +107,13 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_one(&advent_of_code::template::read_file("examples", YEAR, DAY));
         assert_eq!(result, Some(8));
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_two(&advent_of_code::template::read_file("examples", YEAR, DAY));
         assert_eq!(result, Some(2286));
     }
 }
@@ -0,0 +1,59 @@
+use itertools::Itertools;
+use advent_of_code::utils::grid::{Grid, Span};
+use advent_of_code::utils::parse_int_bytes;
+advent_of_code::solution!(2023, 3, parse);
+
+pub fn part_one(map: &EngineMap) -> Option<u32> {
+    let count = map.numbers.iter()
+        .filter(|(span, _)| map.grid.span_neighbors(span).any(|point| is_symbol(map.grid[point])))
+        .map(|(_, value)| value)
+        .sum();
+    Some(count)
+}
+
+pub fn part_two(map: &EngineMap) -> Option<u32> {
+    let count = map.grid.positions(|&cell| cell == b'*')
+        .filter_map(|gear| {
+            let possible_gears = map.numbers.iter()
+                .filter(|(span, _)| span.is_adjacent(gear))
+                .map(|(_, value)| value)
+                .collect_vec();
+            (possible_gears.len() == 2).then(|| possible_gears[0] * possible_gears[1])
+        })
+        .sum();
+    Some(count)
+}
+
+pub fn parse(input: &str) -> EngineMap {
+    let grid = Grid::parse_with(input, |c| c as u8).expect("schematic is a grid");
+    let numbers = grid.spans(u8::is_ascii_digit).into_iter()
+        .map(|span| (span, parse_int_bytes(grid.span_cells(&span)).expect("part numbers fit in u32")))
+        .collect();
+    EngineMap { grid, numbers }
+}
+
+fn is_symbol(cell: u8) -> bool {
+    cell != b'.' && !cell.is_ascii_digit()
+}
+
+pub struct EngineMap {
+    grid: Grid<u8>,
+    numbers: Vec<(Span, u32)>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let result = part_one(&parse(&advent_of_code::template::read_file("examples", YEAR, DAY)));
+        assert_eq!(result, Some(4361));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let result = part_two(&parse(&advent_of_code::template::read_file("examples", YEAR, DAY)));
+        assert_eq!(result, Some(467835));
+    }
+}
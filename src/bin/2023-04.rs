@@ -7,18 +7,23 @@ use nom::multi::{many1, many_till};
 use nom::sequence::Tuple;
 use nom::sequence::delimited;
 
-advent_of_code::solution!(4);
+advent_of_code::solution!(2023, 4, parse);
 
-pub fn part_one(input: &str) -> Option<u32> {
-    let sum = input.lines()
-        .map(parse)
+pub fn parse(input: &str) -> Vec<Game> {
+    input.lines()
+        .map(parse_game)
+        .collect()
+}
+
+pub fn part_one(games: &[Game]) -> Option<u32> {
+    let sum = games.iter()
         .map(|g| g.points())
         .sum();
 
     Some(sum)
 }
 
-fn parse(line: &str) -> Game {
+fn parse_game(line: &str) -> Game {
     let (_, (_, _, (win, _), cards)) = parse_nums(line).unwrap();
     Game {
         win,
@@ -26,7 +31,9 @@ fn parse(line: &str) -> Game {
     }
 }
 
-fn parse_nums(input: &str) -> IResult<&str, (&str, &str, (Vec<u32>, &str), Vec<u32>)> {
+type ParsedNums<'a> = (&'a str, &'a str, (Vec<u32>, &'a str), Vec<u32>);
+
+fn parse_nums(input: &str) -> IResult<&str, ParsedNums<'_>> {
     let until_num = take_until(":");
     let column = tag(":");
     let numbers1 = many_till(delimited(space0, u32_parser, space0), tag("|"));
@@ -35,7 +42,7 @@ fn parse_nums(input: &str) -> IResult<&str, (&str, &str, (Vec<u32>, &str), Vec<u
 }
 
 #[derive(Debug)]
-struct Game {
+pub struct Game {
     win: Vec<u32>,
     cards: Vec<u32>,
 }
@@ -73,9 +80,8 @@ impl Points {
     }
 }
 
-pub fn part_two(input: &str) -> Option<u32> {
-    let counter = input.lines()
-        .map(parse)
+pub fn part_two(games: &[Game]) -> Option<u32> {
+    let counter = games.iter()
         .fold(CopyCounter::default(), |mut acc, g| {
             let current = acc.copies.pop_front().unwrap_or(0u32);
             let current = current + 1;
@@ -106,13 +112,13 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_one(&parse(&advent_of_code::template::read_file("examples", YEAR, DAY)));
         assert_eq!(result, Some(13));
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_two(&parse(&advent_of_code::template::read_file("examples", YEAR, DAY)));
         assert_eq!(result, Some(30));
     }
 }
@@ -0,0 +1,72 @@
+advent_of_code::solution!(2023, 5, parse);
+
+use itertools::Itertools;
+
+use advent_of_code::utils::interval::{Interval, IntervalSet, RangeMap};
+use advent_of_code::utils::{numbers, parse_numbers};
+
+pub struct Almanac {
+    seeds: Vec<i64>,
+    // every map from seed to location composed into one
+    location: RangeMap,
+}
+
+pub fn parse(input: &str) -> Almanac {
+    let mut lines = input.lines();
+    let seeds = numbers(lines.next().expect("almanac starts with the seeds"))
+        .collect();
+    let location = parse_maps(lines).iter()
+        .fold(RangeMap::new(), |location, map| location.then(map));
+    Almanac {
+        seeds,
+        location,
+    }
+}
+
+pub fn part_one(almanac: &Almanac) -> Option<i64> {
+    almanac.seeds.iter()
+        .map(|&seed| almanac.location.map(seed))
+        .min()
+}
+
+fn parse_maps<'a>(lines: impl Iterator<Item = &'a str>) -> Vec<RangeMap> {
+    let mut maps: Vec<RangeMap> = Vec::new();
+    for line in lines {
+        if line.contains("map") {
+            maps.push(RangeMap::new());
+            continue;
+        }
+        if let Some((dest, source, length)) = parse_numbers::<(i64, i64, i64)>(line) {
+            maps.last_mut()
+                .expect("ranges follow a map header")
+                .insert(Interval::with_len(source, length), dest - source);
+        }
+    }
+    maps
+}
+
+pub fn part_two(almanac: &Almanac) -> Option<i64> {
+    let seeds: IntervalSet = almanac.seeds.iter()
+        .tuples()
+        .map(|(&first, &length)| Interval::with_len(first, length))
+        .collect();
+    almanac.location.map_set(&seeds)
+        .min()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let result = part_one(&parse(&advent_of_code::template::read_file("examples", YEAR, DAY)));
+        assert_eq!(result, Some(35));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let result = part_two(&parse(&advent_of_code::template::read_file("examples", YEAR, DAY)));
+        assert_eq!(result, Some(46));
+    }
+}
@@ -0,0 +1,72 @@
+use std::iter::zip;
+use itertools::Itertools;
+use advent_of_code::utils::numbers;
+advent_of_code::solution!(2023, 6);
+
+pub fn part_one(input: &str) -> Option<u64> {
+    let lines = input.lines()
+        .collect_vec();
+    let time_digits = numbers::<u64>(lines[0]);
+    let distance_digits = numbers::<u64>(lines[1]);
+    zip(time_digits, distance_digits)
+        .map(|(time, distance)| calc_wins(time, distance))
+        .reduce(|a, b| a * b)
+}
+
+fn calc_wins(time: u64, distance: u64) -> u64 {
+    let t = time as i64;
+    let d = distance as i64;
+    let disc = (t.pow(2) - 4 * d) as f64;
+    let x1 = (-t as f64 - disc.powf(0.5)) / -2f64;
+    let x2 = (-t as f64 + disc.powf(0.5)) / -2f64;
+
+    let x1i = x1.floor() as i64;
+    let x2i = if x2.ceil() == x2.floor() {
+        x2.floor() as i64 + 1
+    } else {
+        x2.floor() as i64
+    };
+
+    let end = min_under(t, x1i) as u64;
+    let start = min_under(t, x2i) as u64;
+
+    end - start
+}
+
+fn min_under(t: i64, value: i64) -> i64 {
+    if value > t {
+        t
+    } else {
+        value
+    }
+}
+
+/// Reads the numbers of a line as one number, ignoring the spaces between them.
+fn kerned_number(line: &str) -> u64 {
+    numbers::<u64>(line)
+        .fold(0, |acc, n| acc * 10u64.pow(n.checked_ilog10().unwrap_or(0) + 1) + n)
+}
+
+pub fn part_two(input: &str) -> Option<u64> {
+    let (time, distance) = input.lines()
+        .map(kerned_number)
+        .collect_tuple()?;
+    Some(calc_wins(time, distance))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let result = part_one(&advent_of_code::template::read_file("examples", YEAR, DAY));
+        assert_eq!(result, Some(288));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let result = part_two(&advent_of_code::template::read_file("examples", YEAR, DAY));
+        assert_eq!(result, Some(71503));
+    }
+}
@@ -4,13 +4,13 @@ use std::collections::{HashMap};
 
 use itertools::Itertools;
 
-use advent_of_code::utils::to_digits;
-advent_of_code::solution!(7);
+use advent_of_code::utils::parse_int;
+advent_of_code::solution!(2023, 7);
 
 pub fn part_one(input: &str) -> Option<u32> {
     let vec = input.lines()
         .filter_map(|l| l.split_once(' '))
-        .map(|(hand, bid)| (ParsedHand::new_v1(hand), to_digits(bid).unwrap_or_else(|| panic!("bid: {} is digits", bid))))
+        .map(|(hand, bid)| (ParsedHand::new_v1(hand), parse_int::<u32>(bid).unwrap_or_else(|e| panic!("bid: {} is a number: {}", bid, e))))
         .sorted_by(|(h1, _), (h2, _)| cmp(h1, h2, ParsedHand::input_positions_v1))
         // .inspect(|it| eprintln!("it = {:?}", it))
         .collect_vec();
@@ -98,7 +98,6 @@ impl Kind {
                 acc
             });
         let doubles = doubles.into_iter()
-            .map(|(value, count)| (value, count))
             .sorted_by(|(card1, count1), (card2, count2)| {
                 count1.cmp(count2)
                     .then(find_char_pos_v1(*card1)
@@ -159,7 +158,6 @@ impl Kind {
                 acc
             });
         let doubles = doubles.into_iter()
-            .map(|(value, count)| (value, count))
             .sorted_by(|(card1, count1), (card2, count2)| {
                 count1.cmp(count2)
                     .then(find_char_pos_v2(*card1)
@@ -217,7 +215,7 @@ const NEW_LABELS: [char; 13] = ['A', 'K', 'Q', 'T', '9', '8', '7', '6', '5', '4'
 pub fn part_two(input: &str) -> Option<u32> {
     let vec = input.lines()
         .filter_map(|l| l.split_once(' '))
-        .map(|(hand, bid)| (ParsedHand::new_v2(hand), to_digits(bid).unwrap_or_else(|| panic!("bid: {} is digits", bid))))
+        .map(|(hand, bid)| (ParsedHand::new_v2(hand), parse_int::<u32>(bid).unwrap_or_else(|e| panic!("bid: {} is a number: {}", bid, e))))
         // .inspect(|it| eprintln!("it = {:?}", it))
         .sorted_by(|(h1, _), (h2, _)| cmp(h1, h2, ParsedHand::input_positions_v2))
         // .inspect(|it| eprintln!("it = {:?}", it))
@@ -246,13 +244,13 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_one(&advent_of_code::template::read_file("examples", YEAR, DAY));
         assert_eq!(result, Some(6440));
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_two(&advent_of_code::template::read_file("examples", YEAR, DAY));
         assert_eq!(result, Some(5905));
     }
 }
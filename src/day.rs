@@ -114,6 +114,55 @@ impl Iterator for AllDays {
 
 /* -------------------------------------------------------------------------- */
 
+/// A sorted set of days, parsed from a comma-separated list of days and ranges.
+///
+/// ```
+/// # use advent_of_code::{day, DaySet};
+/// let days: DaySet = "1,3,5-7".parse().unwrap();
+/// assert_eq!(days.iter().collect::<Vec<_>>(), [day!(1), day!(3), day!(5), day!(6), day!(7)]);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DaySet(Vec<Day>);
+
+impl DaySet {
+    /// Returns `true` if the set contains the provided day.
+    pub fn contains(&self, day: Day) -> bool {
+        self.0.binary_search(&day).is_ok()
+    }
+
+    /// Iterates the days of the set in ascending order.
+    pub fn iter(&self) -> impl Iterator<Item = Day> + '_ {
+        self.0.iter().copied()
+    }
+}
+
+impl FromStr for DaySet {
+    type Err = DayFromStrError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut days = vec![];
+
+        for item in s.split(',').map(str::trim) {
+            match item.split_once('-') {
+                Some((start, end)) => {
+                    let (start, end): (Day, Day) = (start.trim().parse()?, end.trim().parse()?);
+                    if start > end {
+                        return Err(DayFromStrError);
+                    }
+                    days.extend((start.0..=end.0).map(Day));
+                }
+                None => days.push(item.parse()?),
+            }
+        }
+
+        days.sort_unstable();
+        days.dedup();
+        Ok(Self(days))
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
 /// Creates a [`Day`] value in a const context.
 #[macro_export]
 macro_rules! day {
@@ -134,7 +183,7 @@ macro_rules! day {
 
 #[cfg(feature = "test_lib")]
 mod tests {
-    use super::{all_days, Day};
+    use super::{all_days, Day, DaySet};
 
     #[test]
     fn all_days_iterator() {
@@ -167,6 +216,17 @@ mod tests {
         assert_eq!(iter.next(), Some(Day(25)));
         assert_eq!(iter.next(), None);
     }
+
+    #[test]
+    fn parses_day_sets() {
+        let days: DaySet = "9, 1,5-7,6".parse().unwrap();
+        assert_eq!(days.0, [Day(1), Day(5), Day(6), Day(7), Day(9)]);
+        assert!(days.contains(Day(6)));
+        assert!(!days.contains(Day(8)));
+        assert!("7-5".parse::<DaySet>().is_err());
+        assert!("1,26".parse::<DaySet>().is_err());
+        assert!("".parse::<DaySet>().is_err());
+    }
 }
 
 /* -------------------------------------------------------------------------- */
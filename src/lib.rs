@@ -1,5 +1,10 @@
+// solutions refer to the library by name, which also needs to work when they are compiled into it.
+extern crate self as advent_of_code;
+
 mod day;
 pub mod template;
+mod year;
 
 pub use day::*;
+pub use year::*;
 pub mod utils;
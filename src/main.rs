@@ -1,30 +1,39 @@
-use advent_of_code::template::commands::{all, download, read, scaffold, solve};
+use advent_of_code::template::commands::{
+    all, download, examples, read, scaffold, solve, test_examples,
+};
 use args::{parse, AppArguments};
 
 mod args {
     use std::process;
 
-    use advent_of_code::Day;
+    use advent_of_code::template::commands::{all, solve};
+    use advent_of_code::{Day, Year};
 
     pub enum AppArguments {
         Download {
+            year: Year,
             day: Day,
         },
         Read {
+            year: Year,
             day: Day,
         },
-        Scaffold {
+        Examples {
+            year: Year,
             day: Day,
+            force: bool,
         },
-        Solve {
+        Scaffold {
+            year: Year,
             day: Day,
-            release: bool,
-            time: bool,
-            submit: Option<u8>,
+            answer_type: Option<String>,
+            template: Option<String>,
+            download: bool,
         },
-        All {
-            release: bool,
-            time: bool,
+        Solve(solve::Options),
+        All(all::Options),
+        TestExamples {
+            year: Year,
         },
     }
 
@@ -32,24 +41,59 @@ mod args {
         let mut args = pico_args::Arguments::from_env();
 
         let app_args = match args.subcommand()?.as_deref() {
-            Some("all") => AppArguments::All {
+            Some("all") => AppArguments::All(all::Options {
+                year: year(&mut args)?,
                 release: args.contains("--release"),
                 time: args.contains("--time"),
-            },
+                format: args.opt_value_from_str("--format")?.unwrap_or_default(),
+                bench_time: args.opt_value_from_str("--bench-time")?,
+                compare: args.contains("--compare"),
+                baseline: args.opt_value_from_str("--baseline")?,
+                save_baseline: args.opt_value_from_str("--save-baseline")?,
+                threshold: args.opt_value_from_str("--threshold")?,
+                check: args.contains("--check"),
+                jobs: args.opt_value_from_str("--jobs")?,
+                days: args.opt_value_from_str("--days")?,
+                skip: args.opt_value_from_str("--skip")?,
+                only_solved: args.contains("--only-solved"),
+                isolated: args.contains("--isolated"),
+                profiles: args.opt_value_from_str("--profiles")?,
+            }),
             Some("download") => AppArguments::Download {
+                year: year(&mut args)?,
+                day: args.free_from_str()?,
+            },
+            Some("examples") => AppArguments::Examples {
+                year: year(&mut args)?,
                 day: args.free_from_str()?,
+                force: args.contains("--force"),
             },
             Some("read") => AppArguments::Read {
+                year: year(&mut args)?,
                 day: args.free_from_str()?,
             },
             Some("scaffold") => AppArguments::Scaffold {
+                year: year(&mut args)?,
                 day: args.free_from_str()?,
+                answer_type: args.opt_value_from_str("--type")?,
+                template: args.opt_value_from_str("--template")?,
+                download: args.contains("--download"),
             },
-            Some("solve") => AppArguments::Solve {
+            Some("solve") => AppArguments::Solve(solve::Options {
+                year: year(&mut args)?,
                 day: args.free_from_str()?,
                 release: args.contains("--release"),
                 submit: args.opt_value_from_str("--submit")?,
                 time: args.contains("--time"),
+                format: args.opt_value_from_str("--format")?.unwrap_or_default(),
+                bench_time: args.opt_value_from_str("--bench-time")?,
+                input: args.opt_value_from_str("--input")?,
+                example: args.contains("--example"),
+                watch: args.contains("--watch"),
+                test: args.contains("--test"),
+            }),
+            Some("test-examples") => AppArguments::TestExamples {
+                year: year(&mut args)?,
             },
             Some(x) => {
                 eprintln!("Unknown command: {x}");
@@ -68,6 +112,14 @@ mod args {
 
         Ok(app_args)
     }
+
+    /// Reads the `--year` option, falling back to `AOC_YEAR`.
+    fn year(args: &mut pico_args::Arguments) -> Result<Year, Box<dyn std::error::Error>> {
+        let year: Option<Year> = args.opt_value_from_str("--year")?;
+        Ok(year
+            .or_else(Year::from_env)
+            .ok_or("no year selected, pass `--year` or set `AOC_YEAR` in `.cargo/config.toml`")?)
+    }
 }
 
 fn main() {
@@ -77,16 +129,26 @@ fn main() {
             std::process::exit(1);
         }
         Ok(args) => match args {
-            AppArguments::All { release, time } => all::handle(release, time),
-            AppArguments::Download { day } => download::handle(day),
-            AppArguments::Read { day } => read::handle(day),
-            AppArguments::Scaffold { day } => scaffold::handle(day),
-            AppArguments::Solve {
+            AppArguments::All(options) => all::handle(&options),
+            AppArguments::Download { year, day } => download::handle(year, day),
+            AppArguments::Read { year, day } => read::handle(year, day),
+            AppArguments::Examples { year, day, force } => examples::handle(year, day, force),
+            AppArguments::Scaffold {
+                year,
                 day,
-                release,
-                time,
-                submit,
-            } => solve::handle(day, release, time, submit),
+                answer_type,
+                template,
+                download,
+            } => {
+                let options = scaffold::Options {
+                    answer_type: answer_type.as_deref(),
+                    template: template.as_deref(),
+                    download,
+                };
+                scaffold::handle(year, day, &options);
+            }
+            AppArguments::Solve(options) => solve::handle(&options),
+            AppArguments::TestExamples { year } => test_examples::handle(year),
         },
     };
 }
@@ -0,0 +1,437 @@
+/// Module that keeps a per-day record of submitted answers and their verdicts.
+/// The store is used to avoid resubmitting answers that are known to be wrong and to
+/// remember accepted answers for regression checks.
+use std::collections::HashMap;
+use std::fs::{self, OpenOptions};
+use std::io::{self, Write};
+use std::path::Path;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::template::aoc_client::Verdict;
+use crate::template::json;
+use crate::{Day, Year};
+
+#[derive(Debug)]
+pub enum Error {
+    Parser(String),
+    IO(io::Error),
+}
+
+impl From<std::io::Error> for Error {
+    fn from(e: std::io::Error) -> Self {
+        Error::IO(e)
+    }
+}
+
+/// A submitted answer and the verdict it received.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Submission {
+    pub part: u8,
+    pub answer: String,
+    pub verdict: Verdict,
+    /// Unix timestamp (seconds) of the submission.
+    pub time: u64,
+}
+
+impl Submission {
+    #[must_use]
+    pub fn new(part: u8, answer: &str, verdict: Verdict) -> Self {
+        Self {
+            part,
+            answer: answer.to_string(),
+            verdict,
+            time: SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map_or(0, |d| d.as_secs()),
+        }
+    }
+
+    fn to_json(&self) -> String {
+        json::to_object(&[
+            ("part", self.part.into()),
+            ("answer", self.answer.as_str().into()),
+            ("verdict", verdict_to_str(&self.verdict).into()),
+            ("time", self.time.into()),
+        ])
+    }
+
+    fn from_json(line: &str) -> Option<Self> {
+        let fields = json::parse_object(line)?;
+
+        Some(Self {
+            part: u8::try_from(fields.get("part")?.as_u128()?).ok()?,
+            answer: fields.get("answer")?.as_str()?.to_string(),
+            verdict: verdict_from_str(fields.get("verdict")?.as_str()?)?,
+            time: u64::try_from(fields.get("time")?.as_u128()?).ok()?,
+        })
+    }
+}
+
+fn verdict_to_str(verdict: &Verdict) -> &'static str {
+    match verdict {
+        Verdict::Correct => "correct",
+        Verdict::TooHigh => "too_high",
+        Verdict::TooLow => "too_low",
+        Verdict::Wrong => "wrong",
+        Verdict::RateLimited(_) => "rate_limited",
+        Verdict::WrongLevel => "wrong_level",
+    }
+}
+
+fn verdict_from_str(s: &str) -> Option<Verdict> {
+    let verdict = match s {
+        "correct" => Verdict::Correct,
+        "too_high" => Verdict::TooHigh,
+        "too_low" => Verdict::TooLow,
+        "wrong" => Verdict::Wrong,
+        "rate_limited" => Verdict::RateLimited(String::new()),
+        "wrong_level" => Verdict::WrongLevel,
+        _ => return None,
+    };
+    Some(verdict)
+}
+
+/// Normalizes a rendered answer for submission by trimming surrounding whitespace.
+/// Returns `None` for multi-line answers (e.g. ASCII art), which have to be read and submitted by hand.
+#[must_use]
+pub fn normalize(answer: &str) -> Option<String> {
+    let answer = answer.trim();
+    (!answer.is_empty() && !answer.contains('\n')).then(|| answer.to_string())
+}
+
+/// Whether an answer should be submitted, based on previous submissions.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Guard {
+    Allow,
+    /// The answer can be submitted, but looks suspicious, with an explanation.
+    Warn(String),
+    /// The answer should not be submitted, with an explanation.
+    Refuse(String),
+}
+
+/// Checks a new answer against the previous submissions of a part.
+/// Answers that contradict an earlier _too high_ / _too low_ hint only warn, as the hint may be stale.
+#[must_use]
+pub fn check(submissions: &[Submission], part: u8, answer: &str) -> Guard {
+    let previous: Vec<&Submission> = submissions.iter().filter(|s| s.part == part).collect();
+
+    if let Some(accepted) = previous.iter().find(|s| s.verdict == Verdict::Correct) {
+        return if accepted.answer == answer {
+            Guard::Refuse(format!("{answer} was already accepted for part {part}."))
+        } else {
+            Guard::Refuse(format!(
+                "part {part} was already solved with {}, but the solution now returns {answer}.",
+                accepted.answer
+            ))
+        };
+    }
+
+    if let Some(known) = previous
+        .iter()
+        .find(|s| s.answer == answer && is_wrong(&s.verdict))
+    {
+        return Guard::Refuse(format!(
+            "{answer} was already submitted for part {part} and is {}.",
+            known.verdict
+        ));
+    }
+
+    if let Ok(value) = answer.parse::<i128>() {
+        for s in &previous {
+            let Ok(hint) = s.answer.parse::<i128>() else {
+                continue;
+            };
+
+            let contradicts = match s.verdict {
+                Verdict::TooHigh => value >= hint,
+                Verdict::TooLow => value <= hint,
+                _ => false,
+            };
+
+            if contradicts {
+                return Guard::Warn(format!(
+                    "{answer} contradicts an earlier hint: {hint} is {}.",
+                    s.verdict
+                ));
+            }
+        }
+    }
+
+    Guard::Allow
+}
+
+fn is_wrong(verdict: &Verdict) -> bool {
+    matches!(verdict, Verdict::Wrong | Verdict::TooHigh | Verdict::TooLow)
+}
+
+/// Returns the accepted answer of a part, if any.
+#[must_use]
+pub fn accepted(submissions: &[Submission], part: u8) -> Option<&str> {
+    submissions
+        .iter()
+        .find(|s| s.part == part && s.verdict == Verdict::Correct)
+        .map(|s| s.answer.as_str())
+}
+
+/// Outcome of checking a part's result against its expected answer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Check {
+    Pass,
+    Fail {
+        expected: String,
+        actual: Option<String>,
+    },
+    /// There is no expected answer for this part.
+    Unknown,
+}
+
+/// Checks the result of a part against its expected answer.
+#[must_use]
+pub fn check_result(expected: Option<&str>, actual: Option<&str>) -> Check {
+    match expected {
+        None => Check::Unknown,
+        Some(expected) if Some(expected.trim()) == actual.map(str::trim) => Check::Pass,
+        Some(expected) => Check::Fail {
+            expected: expected.to_string(),
+            actual: actual.map(Into::into),
+        },
+    }
+}
+
+/// Expected answers, keyed by day and part.
+pub type ExpectedAnswers = HashMap<(Day, u8), String>;
+
+/// Parses the expected answers file. Each non-empty line that is not a `#` comment has the form
+/// `<day> <part> <answer>`, e.g. `1 2 281`. Answers can't contain whitespace.
+fn parse_expected(s: &str) -> Result<ExpectedAnswers, Error> {
+    s.lines()
+        .enumerate()
+        .filter(|(_, l)| !l.trim().is_empty() && !l.trim_start().starts_with('#'))
+        .map(|(i, l)| {
+            let error = || Error::Parser(format!("invalid expected answer on line {}.", i + 1));
+            let mut split = l.split_whitespace();
+            let day = split
+                .next()
+                .and_then(|d| d.parse::<Day>().ok())
+                .ok_or_else(error)?;
+            let part = split
+                .next()
+                .and_then(|p| p.parse::<u8>().ok())
+                .ok_or_else(error)?;
+            let answer = split.next().ok_or_else(error)?;
+            if split.next().is_some() {
+                return Err(Error::Parser(format!(
+                    "unexpected text after the expected answer on line {}.",
+                    i + 1
+                )));
+            }
+            Ok(((day, part), answer.to_string()))
+        })
+        .collect()
+}
+
+#[must_use]
+pub fn get_expected_path(year: Year) -> String {
+    format!("data/{year}/answers/expected.txt")
+}
+
+/// Reads the manually maintained expected answers of a year. A missing file is treated as no expected answers.
+pub fn load_expected(year: Year) -> Result<ExpectedAnswers, Error> {
+    load_expected_from(&get_expected_path(year))
+}
+
+/// Reads expected answers from a file in the format of the expected answers file.
+/// A missing file is treated as no expected answers.
+pub fn load_expected_from(path: &str) -> Result<ExpectedAnswers, Error> {
+    if !Path::new(path).exists() {
+        return Ok(HashMap::new());
+    }
+
+    parse_expected(&fs::read_to_string(path)?)
+}
+
+/// Returns the expected answer of a part: the manually maintained answer if present,
+/// the accepted submission otherwise.
+#[must_use]
+pub fn expected_answer(
+    expected: &ExpectedAnswers,
+    submissions: &[Submission],
+    day: Day,
+    part: u8,
+) -> Option<String> {
+    expected
+        .get(&(day, part))
+        .cloned()
+        .or_else(|| accepted(submissions, part).map(Into::into))
+}
+
+#[must_use]
+pub fn get_path(year: Year, day: Day) -> String {
+    format!("data/{year}/answers/{day}.jsonl")
+}
+
+fn parse(s: &str) -> Result<Vec<Submission>, Error> {
+    s.lines()
+        .enumerate()
+        .filter(|(_, l)| !l.trim().is_empty())
+        .map(|(i, l)| {
+            Submission::from_json(l)
+                .ok_or_else(|| Error::Parser(format!("invalid submission on line {}.", i + 1)))
+        })
+        .collect()
+}
+
+/// Reads all submissions of a day. A missing file is treated as no submissions.
+pub fn load(year: Year, day: Day) -> Result<Vec<Submission>, Error> {
+    let path = get_path(year, day);
+
+    if !Path::new(&path).exists() {
+        return Ok(vec![]);
+    }
+
+    parse(&fs::read_to_string(path)?)
+}
+
+/// Appends a submission to the store of a day.
+pub fn record(year: Year, day: Day, submission: &Submission) -> Result<(), Error> {
+    let path = get_path(year, day);
+
+    if let Some(dir) = Path::new(&path).parent() {
+        fs::create_dir_all(dir)?;
+    }
+
+    let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+    writeln!(file, "{}", submission.to_json())?;
+    Ok(())
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{
+        accepted, check, check_result, expected_answer, normalize, parse, parse_expected, Check,
+        Error, Guard, Submission,
+    };
+    use crate::day;
+    use crate::template::aoc_client::Verdict;
+
+    fn submission(part: u8, answer: &str, verdict: Verdict) -> Submission {
+        Submission {
+            part,
+            answer: answer.into(),
+            verdict,
+            time: 1701388800,
+        }
+    }
+
+    #[test]
+    fn round_trip() {
+        let submissions = vec![
+            submission(1, "142", Verdict::TooHigh),
+            submission(1, "141", Verdict::Correct),
+        ];
+        let s = submissions
+            .iter()
+            .map(Submission::to_json)
+            .collect::<Vec<_>>()
+            .join("\n");
+        assert_eq!(parse(&s).unwrap(), submissions);
+    }
+
+    #[test]
+    fn reports_line_of_invalid_submission() {
+        let s = format!(
+            "{}\n\n{{}}",
+            submission(1, "142", Verdict::TooHigh).to_json()
+        );
+        assert!(matches!(parse(&s), Err(Error::Parser(e)) if e.contains("line 3")));
+    }
+
+    #[test]
+    fn allows_new_answers() {
+        let submissions = vec![submission(1, "142", Verdict::Wrong)];
+        assert_eq!(check(&submissions, 1, "143"), Guard::Allow);
+        assert_eq!(check(&submissions, 2, "142"), Guard::Allow);
+    }
+
+    #[test]
+    fn refuses_known_wrong_answers() {
+        let submissions = vec![submission(1, "142", Verdict::Wrong)];
+        assert!(matches!(check(&submissions, 1, "142"), Guard::Refuse(_)));
+    }
+
+    #[test]
+    fn allows_rate_limited_answers() {
+        let submissions = vec![submission(1, "142", Verdict::RateLimited(String::new()))];
+        assert_eq!(check(&submissions, 1, "142"), Guard::Allow);
+    }
+
+    #[test]
+    fn warns_about_answers_contradicting_hints() {
+        let submissions = vec![
+            submission(1, "100", Verdict::TooHigh),
+            submission(1, "50", Verdict::TooLow),
+        ];
+        assert!(matches!(check(&submissions, 1, "120"), Guard::Warn(_)));
+        assert!(matches!(check(&submissions, 1, "40"), Guard::Warn(_)));
+        assert_eq!(check(&submissions, 1, "75"), Guard::Allow);
+    }
+
+    #[test]
+    fn refuses_solved_parts() {
+        let submissions = vec![submission(2, "281", Verdict::Correct)];
+        assert!(matches!(check(&submissions, 2, "281"), Guard::Refuse(_)));
+        assert!(matches!(check(&submissions, 2, "280"), Guard::Refuse(_)));
+        assert_eq!(accepted(&submissions, 2), Some("281"));
+        assert_eq!(accepted(&submissions, 1), None);
+    }
+
+    #[test]
+    fn parses_expected_answers() {
+        let expected = parse_expected("# day part answer\n1 1 142\n\n01 2 281\n").unwrap();
+        assert_eq!(expected.len(), 2);
+        assert_eq!(expected[&(day!(1), 1)], "142");
+        assert_eq!(expected[&(day!(1), 2)], "281");
+        assert!(parse_expected("1 x 142").is_err());
+        assert!(parse_expected("1 1").is_err());
+        assert!(parse_expected("1 1 142 # part one").is_err());
+    }
+
+    #[test]
+    fn prefers_manual_expected_answers() {
+        let expected = parse_expected("1 1 142").unwrap();
+        let submissions = vec![
+            submission(1, "100", Verdict::Correct),
+            submission(2, "281", Verdict::Correct),
+        ];
+        assert_eq!(
+            expected_answer(&expected, &submissions, day!(1), 1),
+            Some("142".into())
+        );
+        assert_eq!(
+            expected_answer(&expected, &submissions, day!(1), 2),
+            Some("281".into())
+        );
+        assert_eq!(expected_answer(&expected, &[], day!(1), 2), None);
+    }
+
+    #[test]
+    fn checks_results() {
+        assert_eq!(check_result(Some("142"), Some("142")), Check::Pass);
+        assert_eq!(check_result(None, Some("142")), Check::Unknown);
+        assert_eq!(
+            check_result(Some("142"), None),
+            Check::Fail {
+                expected: "142".into(),
+                actual: None
+            }
+        );
+    }
+
+    #[test]
+    fn normalizes_answers() {
+        assert_eq!(normalize(" 142\n"), Some("142".into()));
+        assert_eq!(normalize("-12"), Some("-12".into()));
+        assert_eq!(normalize("#..#\n#..#"), None);
+        assert_eq!(normalize("  "), None);
+    }
+}
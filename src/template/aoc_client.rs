@@ -0,0 +1,423 @@
+/// Client for the Advent of Code website.
+///
+/// The session cookie is read from the `AOC_SESSION` environment variable or from an `.adventofcode.session` file
+/// in the working directory or the home directory. `AOC_BASE_URL` overrides the website URL, e.g. for testing
+/// against a local server.
+///
+/// Requests identify themselves with the `AOC_USER_AGENT` environment variable, which should name your repository
+/// and a way to contact you. Without it, only the crate name and version are sent.
+use std::{
+    env,
+    fmt::Display,
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use crate::template::markdown;
+use crate::{Day, Year};
+
+static DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+static SESSION_FILE: &str = ".adventofcode.session";
+static DEFAULT_USER_AGENT: &str = concat!(env!("CARGO_PKG_NAME"), "/", env!("CARGO_PKG_VERSION"));
+
+#[derive(Debug)]
+pub enum AocClientError {
+    SessionNotFound,
+    /// The website answered with a non-success status code.
+    BadStatus(u16),
+    Transport(String),
+    UnexpectedResponse,
+    IoError(io::Error),
+}
+
+impl Display for AocClientError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AocClientError::SessionNotFound => write!(
+                f,
+                "no session cookie found. Set AOC_SESSION or create a \"{SESSION_FILE}\" file."
+            ),
+            AocClientError::BadStatus(status) => {
+                write!(f, "the server responded with status {status}.")
+            }
+            AocClientError::Transport(e) => write!(f, "request failed: {e}"),
+            AocClientError::UnexpectedResponse => {
+                write!(f, "could not understand the server response.")
+            }
+            AocClientError::IoError(e) => write!(f, "could not write output files: {e}"),
+        }
+    }
+}
+
+impl From<io::Error> for AocClientError {
+    fn from(e: io::Error) -> Self {
+        AocClientError::IoError(e)
+    }
+}
+
+impl From<ureq::Error> for AocClientError {
+    fn from(e: ureq::Error) -> Self {
+        match e {
+            ureq::Error::Status(status, _) => AocClientError::BadStatus(status),
+            ureq::Error::Transport(e) => AocClientError::Transport(e.to_string()),
+        }
+    }
+}
+
+/// The verdict of the website on a submitted answer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    TooHigh,
+    TooLow,
+    Wrong,
+    /// An answer was submitted too recently. Contains the website's message.
+    RateLimited(String),
+    /// The part is not open for answers, e.g. because it was already solved.
+    WrongLevel,
+}
+
+impl Verdict {
+    /// Reads the verdict from the message the website returns after a submission.
+    #[must_use]
+    pub fn from_message(message: &str) -> Option<Self> {
+        let verdict = if message.contains("That's the right answer") {
+            Verdict::Correct
+        } else if message.contains("You gave an answer too recently") {
+            Verdict::RateLimited(message.to_string())
+        } else if message.contains("your answer is too high") {
+            Verdict::TooHigh
+        } else if message.contains("your answer is too low") {
+            Verdict::TooLow
+        } else if message.contains("That's not the right answer") {
+            Verdict::Wrong
+        } else if message.contains("You don't seem to be solving the right level") {
+            Verdict::WrongLevel
+        } else {
+            return None;
+        };
+
+        Some(verdict)
+    }
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Verdict::Correct => write!(f, "correct"),
+            Verdict::TooHigh => write!(f, "too high"),
+            Verdict::TooLow => write!(f, "too low"),
+            Verdict::Wrong => write!(f, "wrong"),
+            Verdict::RateLimited(_) => write!(f, "rate-limited"),
+            Verdict::WrongLevel => write!(f, "already solved or locked"),
+        }
+    }
+}
+
+pub struct AocClient {
+    base_url: String,
+    session: String,
+    year: u16,
+    user_agent: String,
+}
+
+impl AocClient {
+    #[must_use]
+    pub fn new(base_url: &str, session: &str, year: u16) -> Self {
+        Self {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session: session.trim().to_string(),
+            year,
+            user_agent: DEFAULT_USER_AGENT.into(),
+        }
+    }
+
+    /// Sets the `User-Agent` sent with every request.
+    #[must_use]
+    pub fn with_user_agent(mut self, user_agent: &str) -> Self {
+        self.user_agent = user_agent.trim().to_string();
+        self
+    }
+
+    /// Creates a client for a year, configured from the environment, see the module documentation.
+    pub fn from_env(year: Year) -> Result<Self, AocClientError> {
+        let session = get_session().ok_or(AocClientError::SessionNotFound)?;
+        let base_url = env::var("AOC_BASE_URL").unwrap_or_else(|_| DEFAULT_BASE_URL.into());
+        let client = Self::new(&base_url, &session, year.into_inner());
+
+        match env::var("AOC_USER_AGENT") {
+            Ok(user_agent) if !user_agent.trim().is_empty() => {
+                Ok(client.with_user_agent(&user_agent))
+            }
+            _ => Ok(client),
+        }
+    }
+
+    fn day_url(&self, day: Day) -> String {
+        format!("{}/{}/day/{}", self.base_url, self.year, day.into_inner())
+    }
+
+    fn get(&self, url: &str) -> Result<String, AocClientError> {
+        let response = ureq::get(url)
+            .set("Cookie", &format!("session={}", self.session))
+            .set("User-Agent", &self.user_agent)
+            .call()?;
+        Ok(response.into_string()?)
+    }
+
+    /// Fetches the puzzle input of a day.
+    pub fn fetch_input(&self, day: Day) -> Result<String, AocClientError> {
+        self.get(&format!("{}/input", self.day_url(day)))
+    }
+
+    /// Fetches the puzzle description of a day as markdown.
+    pub fn fetch_puzzle(&self, day: Day) -> Result<String, AocClientError> {
+        let html = self.get(&self.day_url(day))?;
+        Ok(markdown::from_puzzle_html(&html))
+    }
+
+    /// Submits an answer for one part of a day.
+    pub fn submit(&self, day: Day, part: u8, answer: &str) -> Result<Verdict, AocClientError> {
+        let response = ureq::post(&format!("{}/answer", self.day_url(day)))
+            .set("Cookie", &format!("session={}", self.session))
+            .set("User-Agent", &self.user_agent)
+            .send_form(&[("level", &part.to_string()), ("answer", answer)])?;
+
+        let html = response.into_string()?;
+        let message = markdown::article_text(&html).ok_or(AocClientError::UnexpectedResponse)?;
+        Verdict::from_message(&message).ok_or(AocClientError::UnexpectedResponse)
+    }
+}
+
+/// Fetches the puzzle description of a day, saves it to the puzzle file and prints it.
+pub fn read(year: Year, day: Day) -> Result<(), AocClientError> {
+    let client = AocClient::from_env(year)?;
+    let puzzle = client.fetch_puzzle(day)?;
+    write_file(&get_puzzle_path(year, day), &puzzle)?;
+    println!("{puzzle}");
+    Ok(())
+}
+
+/// Downloads the input and puzzle description of a day, overwriting existing files.
+pub fn download(year: Year, day: Day) -> Result<(), AocClientError> {
+    let client = AocClient::from_env(year)?;
+    let input_path = get_input_path(year, day);
+    let puzzle_path = get_puzzle_path(year, day);
+
+    write_file(&input_path, &client.fetch_input(day)?)?;
+    write_file(&puzzle_path, &client.fetch_puzzle(day)?)?;
+
+    println!("🎄 Successfully wrote input to \"{}\".", &input_path);
+    println!("🎄 Successfully wrote puzzle to \"{}\".", &puzzle_path);
+    Ok(())
+}
+
+/// Downloads the input of a day unless it has been downloaded already, and the latest puzzle description.
+/// The puzzle description is always fetched, as part two only shows up once part one has been solved.
+pub fn download_missing(year: Year, day: Day) -> Result<(), AocClientError> {
+    let client = AocClient::from_env(year)?;
+    let input_path = get_input_path(year, day);
+    let puzzle_path = get_puzzle_path(year, day);
+
+    let has_input = fs::read_to_string(&input_path).is_ok_and(|s| !s.trim().is_empty());
+    if has_input {
+        println!(
+            "Input file \"{}\" already exists, skipping download.",
+            &input_path
+        );
+    } else {
+        write_file(&input_path, &client.fetch_input(day)?)?;
+        println!("🎄 Successfully wrote input to \"{}\".", &input_path);
+    }
+
+    write_file(&puzzle_path, &client.fetch_puzzle(day)?)?;
+    println!("🎄 Successfully wrote puzzle to \"{}\".", &puzzle_path);
+    Ok(())
+}
+
+/// Submits an answer and prints the website's verdict.
+pub fn submit(year: Year, day: Day, part: u8, result: &str) -> Result<Verdict, AocClientError> {
+    let client = AocClient::from_env(year)?;
+    let verdict = client.submit(day, part, result)?;
+
+    match &verdict {
+        Verdict::RateLimited(message) => println!("{message}"),
+        verdict => println!("Answer {result} for day {day} part {part} is {verdict}."),
+    }
+
+    Ok(verdict)
+}
+
+fn write_file(path: &str, contents: &str) -> Result<(), io::Error> {
+    if let Some(dir) = Path::new(path).parent() {
+        fs::create_dir_all(dir)?;
+    }
+    fs::write(path, contents)
+}
+
+fn get_input_path(year: Year, day: Day) -> String {
+    format!("data/{year}/inputs/{day}.txt")
+}
+
+fn get_puzzle_path(year: Year, day: Day) -> String {
+    format!("data/{year}/puzzles/{day}.md")
+}
+
+fn get_session() -> Option<String> {
+    let non_empty = |s: String| Some(s.trim().to_string()).filter(|s| !s.is_empty());
+
+    if let Some(session) = env::var("AOC_SESSION").ok().and_then(non_empty) {
+        return Some(session);
+    }
+
+    let mut candidates = vec![PathBuf::from(SESSION_FILE)];
+    if let Some(home) = env::var_os("HOME").or_else(|| env::var_os("USERPROFILE")) {
+        candidates.push(PathBuf::from(home).join(SESSION_FILE));
+    }
+
+    candidates
+        .iter()
+        .find_map(|path| fs::read_to_string(path).ok().and_then(non_empty))
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::io::{BufRead, BufReader, Read, Write};
+    use std::net::TcpListener;
+    use std::thread;
+
+    use super::{AocClient, AocClientError, Verdict};
+    use crate::day;
+
+    /// Serves a single request with the given status and body, returns the base url and
+    /// a handle yielding the raw request.
+    fn serve_once(status: u16, body: &'static str) -> (String, thread::JoinHandle<String>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+
+        let handle = thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut reader = BufReader::new(stream.try_clone().unwrap());
+            let mut request = String::new();
+            let mut content_length = 0;
+
+            loop {
+                let mut line = String::new();
+                reader.read_line(&mut line).unwrap();
+                if let Some(len) = line.to_lowercase().strip_prefix("content-length:") {
+                    content_length = len.trim().parse().unwrap();
+                }
+                request.push_str(&line);
+                if line == "\r\n" {
+                    break;
+                }
+            }
+
+            let mut content = vec![0; content_length];
+            reader.read_exact(&mut content).unwrap();
+            request.push_str(&String::from_utf8(content).unwrap());
+
+            write!(
+                stream,
+                "HTTP/1.1 {status} X\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                body.len()
+            )
+            .unwrap();
+            request
+        });
+
+        (url, handle)
+    }
+
+    #[test]
+    fn fetches_input() {
+        let (url, handle) = serve_once(200, "1abc2\n");
+        let client = AocClient::new(&url, "secret", 2023);
+
+        assert_eq!(client.fetch_input(day!(1)).unwrap(), "1abc2\n");
+
+        let request = handle.join().unwrap();
+        assert!(request.starts_with("GET /2023/day/1/input "));
+        assert!(request.contains("session=secret"));
+        assert!(request
+            .to_lowercase()
+            .contains(&format!("user-agent: {}", super::DEFAULT_USER_AGENT)));
+    }
+
+    #[test]
+    fn sends_configured_user_agent() {
+        let (url, handle) = serve_once(200, "");
+        let client = AocClient::new(&url, "secret", 2023)
+            .with_user_agent("github.com/someone/aoc by someone@example.com");
+
+        client.fetch_input(day!(1)).unwrap();
+
+        let request = handle.join().unwrap().to_lowercase();
+        assert!(request.contains("user-agent: github.com/someone/aoc by someone@example.com\r\n"));
+    }
+
+    #[test]
+    fn fetches_puzzle_as_markdown() {
+        let (url, _) = serve_once(
+            200,
+            r#"<main><article class="day-desc"><h2>--- Day 5: If You Give A Seed A Fertilizer ---</h2></article></main>"#,
+        );
+        let client = AocClient::new(&url, "secret", 2023);
+
+        assert_eq!(
+            client.fetch_puzzle(day!(5)).unwrap(),
+            "## --- Day 5: If You Give A Seed A Fertilizer ---\n"
+        );
+    }
+
+    #[test]
+    fn submits_answer() {
+        let (url, handle) = serve_once(
+            200,
+            "<main><article><p>That's not the right answer; your answer is too high.</p></article></main>",
+        );
+        let client = AocClient::new(&url, "secret", 2023);
+
+        assert_eq!(client.submit(day!(2), 1, "8").unwrap(), Verdict::TooHigh);
+
+        let request = handle.join().unwrap();
+        assert!(request.starts_with("POST /2023/day/2/answer "));
+        assert!(request.ends_with("level=1&answer=8"));
+    }
+
+    #[test]
+    fn reports_bad_status() {
+        let (url, _) = serve_once(404, "not found");
+        let client = AocClient::new(&url, "secret", 2023);
+
+        assert!(matches!(
+            client.fetch_input(day!(1)),
+            Err(AocClientError::BadStatus(404))
+        ));
+    }
+
+    #[test]
+    fn parses_verdicts() {
+        assert_eq!(
+            Verdict::from_message("That's the right answer! You are one gold star closer."),
+            Some(Verdict::Correct)
+        );
+        assert_eq!(
+            Verdict::from_message("That's not the right answer; your answer is too low."),
+            Some(Verdict::TooLow)
+        );
+        assert_eq!(
+            Verdict::from_message("That's not the right answer. If you're stuck, ..."),
+            Some(Verdict::Wrong)
+        );
+        assert!(matches!(
+            Verdict::from_message("You gave an answer too recently; you have 42s left to wait."),
+            Some(Verdict::RateLimited(_))
+        ));
+        assert_eq!(
+            Verdict::from_message("You don't seem to be solving the right level."),
+            Some(Verdict::WrongLevel)
+        );
+        assert_eq!(Verdict::from_message("Hello"), None);
+    }
+}
@@ -0,0 +1,311 @@
+/// Module that persists timed runs to a local history file and compares runs against each other.
+/// Each line of the history file is a JSON object describing one part of one run.
+use std::fs::{self, OpenOptions};
+use std::io::{self, Write};
+use std::path::Path;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use crate::template::json::{self, Value};
+use crate::template::readme_benchmarks::Timings;
+use crate::{Day, Year};
+
+#[derive(Debug)]
+pub enum Error {
+    Parser(String),
+    /// No timings were saved under the named baseline with the build profile of the current run.
+    UnknownBaseline(String),
+    IO(io::Error),
+}
+
+impl From<std::io::Error> for Error {
+    fn from(e: std::io::Error) -> Self {
+        Error::IO(e)
+    }
+}
+
+/// The timing of a single part in a persisted run.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Entry {
+    /// Unix timestamp (seconds) identifying the run.
+    pub run: u64,
+    /// Optional baseline name this run was saved under.
+    pub baseline: Option<String>,
+    pub release: bool,
+    pub day: Day,
+    pub part: u8,
+    pub mean: Duration,
+    pub median: Duration,
+    pub samples: u128,
+}
+
+impl Entry {
+    fn to_json(&self) -> String {
+        json::to_object(&[
+            ("run", self.run.into()),
+            ("baseline", self.baseline.clone().into()),
+            ("release", self.release.into()),
+            ("day", self.day.into_inner().into()),
+            ("part", self.part.into()),
+            ("mean_nanos", self.mean.as_nanos().into()),
+            ("median_nanos", self.median.as_nanos().into()),
+            ("samples", self.samples.into()),
+        ])
+    }
+
+    fn from_json(line: &str) -> Option<Self> {
+        let fields = json::parse_object(line)?;
+        let nanos = |key: &str| {
+            let nanos = fields.get(key)?.as_u128()?;
+            u64::try_from(nanos).ok().map(Duration::from_nanos)
+        };
+
+        Some(Self {
+            run: u64::try_from(fields.get("run")?.as_u128()?).ok()?,
+            baseline: match fields.get("baseline")? {
+                Value::Null => None,
+                value => Some(value.as_str()?.to_string()),
+            },
+            release: fields.get("release")? == &Value::Bool(true),
+            day: Day::new(u8::try_from(fields.get("day")?.as_u128()?).ok()?)?,
+            part: u8::try_from(fields.get("part")?.as_u128()?).ok()?,
+            mean: nanos("mean_nanos")?,
+            median: nanos("median_nanos")?,
+            samples: fields.get("samples")?.as_u128()?,
+        })
+    }
+}
+
+/// The run a comparison is made against.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Baseline {
+    /// The most recent previous timing of each part.
+    Last,
+    /// The most recent timing of each part that was saved under the given name.
+    Named(String),
+}
+
+/// The comparison of a part's median time between a baseline and the current run.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Comparison {
+    pub day: Day,
+    pub part: u8,
+    pub baseline: Duration,
+    pub current: Duration,
+    /// Relative change in percent, positive values are slowdowns.
+    pub change: f64,
+    pub regressed: bool,
+}
+
+/// Converts the timings of a run into history entries. Parts without benchmark statistics are skipped.
+#[must_use]
+pub fn entries_from_timings(
+    timings: &[Timings],
+    baseline: Option<&str>,
+    release: bool,
+) -> Vec<Entry> {
+    let run = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |d| d.as_secs());
+
+    timings
+        .iter()
+        .flat_map(|t| [(t.day, 1, t.part_1_stats), (t.day, 2, t.part_2_stats)])
+        .filter_map(|(day, part, stats)| {
+            let stats = stats?;
+            Some(Entry {
+                run,
+                baseline: baseline.map(Into::into),
+                release,
+                day,
+                part,
+                mean: stats.mean,
+                median: stats.median,
+                samples: stats.samples,
+            })
+        })
+        .collect()
+}
+
+/// Compares the current run against a baseline in the history.
+/// Only runs with the same build profile are considered, parts missing from the baseline are skipped.
+/// A part regresses if its median is more than `threshold` percent slower than the baseline's median.
+/// Fails if a named baseline has no timings with the build profile of the current run.
+pub fn compare(
+    history: &[Entry],
+    current: &[Entry],
+    baseline: &Baseline,
+    threshold: f64,
+) -> Result<Vec<Comparison>, Error> {
+    if let Baseline::Named(name) = baseline {
+        let known = history.iter().any(|h| {
+            h.baseline.as_deref() == Some(name) && current.iter().any(|c| c.release == h.release)
+        });
+
+        if !known {
+            return Err(Error::UnknownBaseline(name.clone()));
+        }
+    }
+
+    let comparisons = current
+        .iter()
+        .filter_map(|entry| {
+            let previous = history
+                .iter()
+                .filter(|h| h.day == entry.day && h.part == entry.part)
+                .filter(|h| h.release == entry.release)
+                .filter(|h| match baseline {
+                    Baseline::Last => true,
+                    Baseline::Named(name) => h.baseline.as_deref() == Some(name),
+                })
+                .max_by_key(|h| h.run)?;
+
+            #[allow(clippy::cast_precision_loss)]
+            let change = (entry.median.as_nanos() as f64 - previous.median.as_nanos() as f64)
+                / (previous.median.as_nanos().max(1) as f64)
+                * 100.0;
+
+            Some(Comparison {
+                day: entry.day,
+                part: entry.part,
+                baseline: previous.median,
+                current: entry.median,
+                change,
+                regressed: change > threshold,
+            })
+        })
+        .collect();
+
+    Ok(comparisons)
+}
+
+fn parse_history(s: &str) -> Result<Vec<Entry>, Error> {
+    s.lines()
+        .enumerate()
+        .filter(|(_, l)| !l.trim().is_empty())
+        .map(|(i, l)| {
+            Entry::from_json(l)
+                .ok_or_else(|| Error::Parser(format!("invalid history entry on line {}.", i + 1)))
+        })
+        .collect()
+}
+
+#[must_use]
+pub fn get_path(year: Year) -> String {
+    format!("data/{year}/benchmarks/history.jsonl")
+}
+
+/// Reads all entries of the history file of a year. A missing file is treated as an empty history.
+pub fn load(year: Year) -> Result<Vec<Entry>, Error> {
+    let path = get_path(year);
+
+    if !Path::new(&path).exists() {
+        return Ok(vec![]);
+    }
+
+    parse_history(&fs::read_to_string(path)?)
+}
+
+/// Appends entries to the history file of a year, creating it if necessary.
+pub fn append(year: Year, entries: &[Entry]) -> Result<(), Error> {
+    let path = get_path(year);
+
+    if let Some(dir) = Path::new(&path).parent() {
+        fs::create_dir_all(dir)?;
+    }
+
+    let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+
+    for entry in entries {
+        writeln!(file, "{}", entry.to_json())?;
+    }
+
+    Ok(())
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::time::Duration;
+
+    use super::{compare, parse_history, Baseline, Entry, Error};
+    use crate::day;
+
+    fn entry(run: u64, baseline: Option<&str>, part: u8, median: u64) -> Entry {
+        Entry {
+            run,
+            baseline: baseline.map(Into::into),
+            release: true,
+            day: day!(1),
+            part,
+            mean: Duration::from_nanos(median),
+            median: Duration::from_nanos(median),
+            samples: 100,
+        }
+    }
+
+    #[test]
+    fn round_trip() {
+        let entries = vec![entry(1, Some("v1"), 1, 100), entry(2, None, 2, 200)];
+        let s = entries
+            .iter()
+            .map(Entry::to_json)
+            .collect::<Vec<_>>()
+            .join("\n");
+        assert_eq!(parse_history(&s).unwrap(), entries);
+    }
+
+    #[test]
+    fn rejects_malformed_history() {
+        assert!(parse_history("{\"run\":1}").is_err());
+
+        let s = format!("{}\n\n{{\"run\":1}}", entry(1, None, 1, 100).to_json());
+        assert!(matches!(parse_history(&s), Err(Error::Parser(e)) if e.contains("line 3")));
+    }
+
+    #[test]
+    fn compares_against_last_run() {
+        let history = vec![entry(1, None, 1, 100), entry(2, None, 1, 200)];
+        let result = compare(&history, &[entry(3, None, 1, 230)], &Baseline::Last, 10.0).unwrap();
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].baseline, Duration::from_nanos(200));
+        assert!((result[0].change - 15.0).abs() < 1e-9);
+        assert!(result[0].regressed);
+    }
+
+    #[test]
+    fn compares_against_named_baseline() {
+        let history = vec![entry(1, Some("v1"), 1, 250), entry(2, None, 1, 100)];
+        let result = compare(
+            &history,
+            &[entry(3, None, 1, 240)],
+            &Baseline::Named("v1".into()),
+            10.0,
+        )
+        .unwrap();
+        assert_eq!(result[0].baseline, Duration::from_nanos(250));
+        assert!(!result[0].regressed);
+    }
+
+    #[test]
+    fn skips_parts_without_baseline() {
+        let history = vec![entry(1, None, 1, 100)];
+        let result = compare(&history, &[entry(2, None, 2, 100)], &Baseline::Last, 10.0).unwrap();
+        assert!(result.is_empty());
+    }
+
+    #[test]
+    fn rejects_unknown_baseline() {
+        let mut debug = entry(1, Some("v1"), 1, 100);
+        debug.release = false;
+        let history = vec![debug, entry(2, Some("v2"), 1, 100)];
+
+        for name in ["v1", "v3"] {
+            let result = compare(
+                &history,
+                &[entry(3, None, 1, 100)],
+                &Baseline::Named(name.into()),
+                10.0,
+            );
+            assert!(matches!(result, Err(Error::UnknownBaseline(n)) if n == name));
+        }
+    }
+}
@@ -1,45 +1,538 @@
-use std::io;
+use std::{
+    collections::BTreeMap,
+    io,
+    path::Path,
+    process,
+    sync::{mpsc, Mutex},
+    thread,
+};
 
 use crate::template::{
+    answers::{self, Check, ExpectedAnswers},
+    benchmark_history::{self, Baseline, Comparison},
+    get_path_for_bin, profiles,
     readme_benchmarks::{self, Timings},
-    ANSI_BOLD, ANSI_ITALIC, ANSI_RESET,
+    record::{OutputFormat, PartRecord, Status},
+    registry, ANSI_BOLD, ANSI_ITALIC, ANSI_RESET,
 };
-use crate::{all_days, Day};
+use crate::{all_days, Day, DaySet, Year};
+
+/// Options of the `all` command.
+#[derive(Debug, Clone)]
+pub struct Options {
+    pub year: Year,
+    pub release: bool,
+    pub time: bool,
+    pub format: OutputFormat,
+    pub bench_time: Option<u64>,
+    /// Compare timings against the benchmark history and fail on regressions.
+    pub compare: bool,
+    /// Compare against the baseline saved under this name instead of the last run.
+    pub baseline: Option<String>,
+    /// Save this run under a baseline name.
+    pub save_baseline: Option<String>,
+    /// Allowed slowdown in percent before a part counts as regressed.
+    pub threshold: Option<f64>,
+    /// Check results against the expected answers and fail on mismatches.
+    pub check: bool,
+    /// Number of days to run concurrently. Timed runs are always sequential.
+    pub jobs: Option<usize>,
+    /// Only run these days.
+    pub days: Option<DaySet>,
+    /// Do not run these days.
+    pub skip: Option<DaySet>,
+    /// Only run days that have a solution.
+    pub only_solved: bool,
+    /// Run every day in its own solution binary instead of in-process.
+    pub isolated: bool,
+    /// Check the answers for the inputs of these profiles instead of running the puzzle inputs.
+    pub profiles: Option<profiles::Selection>,
+}
 
-pub fn handle(is_release: bool, is_timed: bool) {
-    let mut timings: Vec<Timings> = vec![];
+/// Results of running the selected days against the inputs of a profile.
+#[derive(Default)]
+struct Results {
+    timings: Vec<Timings>,
+    checks: Vec<(Day, [Check; 2])>,
+    statuses: Vec<(Day, u8, Status)>,
+    failures: Vec<(Day, u8, String)>,
+}
 
-    all_days().for_each(|day| {
-        if day > 1 {
-            println!();
-        }
+impl Results {
+    fn has_failures(&self) -> bool {
+        !self.failures.is_empty()
+            || self
+                .checks
+                .iter()
+                .flat_map(|(_, c)| c)
+                .any(|c| matches!(c, Check::Fail { .. }))
+    }
 
-        println!("{ANSI_BOLD}Day {day}{ANSI_RESET}");
-        println!("------");
+    /// Number of parts that were checked against an expected answer, and of those without one.
+    fn checked_parts(&self) -> (usize, usize) {
+        let (unknown, checked): (Vec<&Check>, Vec<&Check>) = self
+            .checks
+            .iter()
+            .flat_map(|(_, c)| c)
+            .partition(|c| **c == Check::Unknown);
+        (checked.len(), unknown.len())
+    }
+}
 
-        let output = child_commands::run_solution(day, is_timed, is_release).unwrap();
+const DEFAULT_REGRESSION_THRESHOLD: f64 = 10.0;
 
-        if output.is_empty() {
-            println!("Not solved.");
-        } else {
-            let val = child_commands::parse_exec_time(&output, day);
-            timings.push(val);
-        }
-    });
+pub fn handle(options: &Options) {
+    let format = options.format;
+
+    if let Some(selection) = &options.profiles {
+        check_profiles(options, selection);
+        return;
+    }
+
+    if options.compare && !options.time {
+        eprintln!("Warning: `--compare` has no effect without `--time`.");
+    }
+
+    let expected = if options.check {
+        answers::load_expected(options.year).unwrap_or_else(|e| {
+            eprintln!("Failed to read expected answers: {e:?}");
+            process::exit(1);
+        })
+    } else {
+        answers::ExpectedAnswers::new()
+    };
+
+    let days = selected_days(options);
+    let results = run_days(options, &days, profiles::DEFAULT, &expected);
 
-    if is_timed {
+    print_summary(&results.statuses, &results.failures, format);
+
+    if options.check {
+        print_checks(&results.checks, format);
+    }
+
+    let has_failures = results.has_failures();
+    let timings = results.timings;
+
+    if options.time {
         let total_millis = timings.iter().map(|x| x.total_nanos).sum::<f64>() / 1_000_000_f64;
 
-        println!("\n{ANSI_BOLD}Total:{ANSI_RESET} {ANSI_ITALIC}{total_millis:.2}ms{ANSI_RESET}");
+        if format == OutputFormat::Text {
+            println!(
+                "\n{ANSI_BOLD}Total:{ANSI_RESET} {ANSI_ITALIC}{total_millis:.2}ms{ANSI_RESET}"
+            );
+        }
 
-        if is_release {
-            match readme_benchmarks::update(timings, total_millis) {
+        let has_regressions = update_history(&timings, options);
+
+        if options.release {
+            // status messages go to stderr in JSON mode to keep stdout machine-readable.
+            match readme_benchmarks::update(options.year, &days, timings) {
+                Ok(()) if format == OutputFormat::Json => {
+                    eprintln!("Successfully updated README with benchmarks.");
+                }
                 Ok(()) => println!("Successfully updated README with benchmarks."),
                 Err(_) => {
                     eprintln!("Failed to update readme with benchmarks.");
                 }
             }
         }
+
+        if has_regressions {
+            process::exit(1);
+        }
+    }
+
+    if has_failures {
+        process::exit(1);
+    }
+}
+
+/// Runs the selected days against the inputs of each selected profile and checks their answers.
+fn check_profiles(options: &Options, selection: &profiles::Selection) {
+    if options.time {
+        eprintln!("Warning: timings are not recorded for profiles, ignoring `--time`.");
+    }
+
+    let options = Options {
+        time: false,
+        check: true,
+        ..options.clone()
+    };
+
+    let names = profiles::list(options.year)
+        .map_err(|e| format!("could not read profiles: {e}"))
+        .and_then(|available| profiles::resolve(selection, &available))
+        .unwrap_or_else(|e| {
+            eprintln!("Failed to select profiles: {e}");
+            process::exit(1);
+        });
+
+    // the report is printed to stderr in JSON mode to keep stdout machine-readable.
+    let print = |line: String| match options.format {
+        OutputFormat::Text => println!("{line}"),
+        OutputFormat::Json => eprintln!("{line}"),
+    };
+
+    let days = selected_days(&options);
+    let mut failed = vec![];
+
+    for (i, profile) in names.iter().enumerate() {
+        if i > 0 {
+            print(String::new());
+        }
+        print(format!("{ANSI_BOLD}Profile {profile}{ANSI_RESET}"));
+        print("==========".into());
+
+        let expected = profiles::load_expected(options.year, profile).unwrap_or_else(|e| {
+            eprintln!("Failed to read expected answers of profile \"{profile}\": {e:?}");
+            process::exit(1);
+        });
+
+        // profiles of other accounts usually only hold the inputs of some days.
+        let days: Vec<Day> = days
+            .iter()
+            .copied()
+            .filter(|&day| {
+                profile == profiles::DEFAULT
+                    || Path::new(&profiles::get_input_path(options.year, profile, day)).exists()
+            })
+            .collect();
+
+        let results = run_days(&options, &days, profile, &expected);
+        print_summary(&results.statuses, &results.failures, options.format);
+        print_checks(&results.checks, options.format);
+
+        // a profile without expected answers would pass without checking anything.
+        let (checked, unknown) = results.checked_parts();
+        if checked == 0 {
+            eprintln!("{ANSI_BOLD}Warning:{ANSI_RESET} profile \"{profile}\" has no expected answers for its inputs, nothing was checked.");
+            failed.push(profile.as_str());
+        } else if unknown > 0 {
+            eprintln!("{ANSI_BOLD}Warning:{ANSI_RESET} profile \"{profile}\" has no expected answer for {unknown} of its parts.");
+        }
+
+        if results.has_failures() && !failed.contains(&profile.as_str()) {
+            failed.push(profile.as_str());
+        }
+    }
+
+    print(String::new());
+    print(format!(
+        "{ANSI_BOLD}Profiles:{ANSI_RESET} {} passed, {} failed.",
+        names.len() - failed.len(),
+        failed.len()
+    ));
+
+    if !failed.is_empty() {
+        print(format!("Failed profiles: {}", failed.join(", ")));
+        process::exit(1);
+    }
+}
+
+/// Runs the selected days against the inputs of a profile.
+/// Results are checked against `expected` and, for the default profile, accepted submissions if `--check` is set.
+fn run_days(options: &Options, days: &[Day], profile: &str, expected: &ExpectedAnswers) -> Results {
+    let format = options.format;
+    let mut results = Results::default();
+
+    let mut handle_records = |day: Day, records: Vec<PartRecord>| {
+        if options.check {
+            // submissions belong to the account of the default profile.
+            let submissions = if profile == profiles::DEFAULT {
+                answers::load(options.year, day).unwrap_or_default()
+            } else {
+                vec![]
+            };
+            let check = |part: u8| {
+                let actual = records.iter().find(|r| r.part == part);
+                answers::check_result(
+                    answers::expected_answer(expected, &submissions, day, part).as_deref(),
+                    actual.and_then(|r| r.answer.as_deref()),
+                )
+            };
+            let result = [check(1), check(2)];
+
+            // days without results only show up if an answer is expected, e.g. because they crashed.
+            if !records.is_empty() || result.iter().any(|c| *c != Check::Unknown) {
+                results.checks.push((day, result));
+            }
+        }
+
+        for record in &records {
+            results.statuses.push((day, record.part, record.status));
+            if let Some(error) = &record.error {
+                results.failures.push((day, record.part, error.clone()));
+            }
+        }
+
+        if records.is_empty() {
+            if format == OutputFormat::Text {
+                println!("Not solved.");
+            }
+        } else if options.time {
+            results
+                .timings
+                .push(child_commands::timings_from_records(&records, day));
+        }
+    };
+
+    let jobs = match options.jobs {
+        Some(jobs) if jobs > 1 && options.time => {
+            eprintln!("Warning: timed runs are not parallelized, ignoring `--jobs`.");
+            1
+        }
+        jobs => jobs.unwrap_or(1),
+    };
+
+    // without the registry, the solutions are not part of this binary.
+    if options.isolated || !registry::ENABLED {
+        if jobs > 1 {
+            if let Err(e) = child_commands::build_solutions(options) {
+                eprintln!("Failed to build solutions: {e:?}");
+                process::exit(1);
+            }
+
+            run_ordered(
+                days,
+                jobs,
+                |day| child_commands::run_executable(day, options, profile),
+                |i, day, output| {
+                    print_header(i, day, format);
+                    handle_records(day, output.replay(format));
+                },
+            );
+        } else {
+            for (i, &day) in days.iter().enumerate() {
+                print_header(i, day, format);
+                let records = child_commands::run_solution(day, options, profile).unwrap();
+                handle_records(day, records);
+            }
+        }
+    } else {
+        if options.release && cfg!(debug_assertions) {
+            eprintln!("Warning: solutions run in this debug build, use `--isolated` to run them in release mode.");
+        }
+
+        run_ordered(
+            days,
+            jobs,
+            |day| in_process::run_day(day, options, profile),
+            |i, day, records| {
+                print_header(i, day, format);
+                in_process::print_records(&records, format);
+                handle_records(day, records);
+            },
+        );
+    }
+
+    results
+}
+
+/// Runs `work` for all days on `jobs` threads.
+/// The result of each day is passed to `on_day` in the order of `days`, together with its index.
+fn run_ordered<T: Send>(
+    days: &[Day],
+    jobs: usize,
+    work: impl Fn(Day) -> T + Sync,
+    mut on_day: impl FnMut(usize, Day, T),
+) {
+    let queue = Mutex::new(days.iter().copied().enumerate());
+    let (tx, rx) = mpsc::channel();
+
+    thread::scope(|scope| {
+        for _ in 0..jobs.clamp(1, days.len().max(1)) {
+            let tx = tx.clone();
+            let (queue, work) = (&queue, &work);
+            scope.spawn(move || loop {
+                let Some((i, day)) = queue.lock().unwrap().next() else {
+                    break;
+                };
+                if tx.send((i, work(day))).is_err() {
+                    break;
+                }
+            });
+        }
+
+        drop(tx);
+
+        // hold back finished days until all days before them have been handled.
+        let mut pending = BTreeMap::new();
+        let mut next = 0;
+
+        for (i, result) in rx {
+            pending.insert(i, result);
+
+            while let Some(result) = pending.remove(&next) {
+                on_day(next, days[next], result);
+                next += 1;
+            }
+        }
+    });
+}
+
+/// Returns the days selected by `--days`, `--skip` and `--only-solved`.
+fn selected_days(options: &Options) -> Vec<Day> {
+    all_days()
+        .filter(|&day| options.days.as_ref().is_none_or(|d| d.contains(day)))
+        .filter(|&day| options.skip.as_ref().is_none_or(|d| !d.contains(day)))
+        .filter(|&day| {
+            !options.only_solved || Path::new(&get_path_for_bin(options.year, day)).exists()
+        })
+        .collect()
+}
+
+fn print_header(index: usize, day: Day, format: OutputFormat) {
+    if format == OutputFormat::Text {
+        if index > 0 {
+            println!();
+        }
+
+        println!("{ANSI_BOLD}Day {day}{ANSI_RESET}");
+        println!("------");
+    }
+}
+
+/// Prints how many parts were solved, unsolved or failed, followed by the errors of failed parts.
+fn print_summary(
+    statuses: &[(Day, u8, Status)],
+    failures: &[(Day, u8, String)],
+    format: OutputFormat,
+) {
+    // the summary is printed to stderr in JSON mode to keep stdout machine-readable.
+    let print = |line: String| match format {
+        OutputFormat::Text => println!("{line}"),
+        OutputFormat::Json => eprintln!("{line}"),
+    };
+
+    let count = |status: Status| statuses.iter().filter(|(.., s)| *s == status).count();
+
+    print(String::new());
+    print(format!(
+        "{ANSI_BOLD}Parts:{ANSI_RESET} {} solved, {} unsolved, {} failed.",
+        count(Status::Solved),
+        count(Status::Unsolved),
+        count(Status::Failed),
+    ));
+
+    for (day, part, error) in failures {
+        print(format!("Day {day} Part {part} failed: {error}"));
+    }
+}
+
+fn print_checks(checks: &[(Day, [Check; 2])], format: OutputFormat) {
+    // the report is printed to stderr in JSON mode to keep stdout machine-readable.
+    let print = |line: String| match format {
+        OutputFormat::Text => println!("{line}"),
+        OutputFormat::Json => eprintln!("{line}"),
+    };
+
+    let cell = |check: &Check| match check {
+        Check::Pass => "✔".to_string(),
+        Check::Unknown => "?".to_string(),
+        Check::Fail { expected, actual } => format!(
+            "✖ expected {expected}, got {}",
+            actual.as_deref().unwrap_or("nothing")
+        ),
+    };
+
+    print(String::new());
+    print(format!("{ANSI_BOLD}Answer check:{ANSI_RESET}"));
+    print("| Day | Part 1 | Part 2 |".into());
+    print("| :---: | :---: | :---: |".into());
+
+    for (day, [part_1, part_2]) in checks {
+        print(format!("| {day} | {} | {} |", cell(part_1), cell(part_2)));
+    }
+
+    let count = |f: fn(&Check) -> bool| checks.iter().flat_map(|(_, c)| c).filter(|c| f(c)).count();
+    print(format!(
+        "{} passed, {} failed, {} without expected answer.",
+        count(|c| *c == Check::Pass),
+        count(|c| matches!(c, Check::Fail { .. })),
+        count(|c| *c == Check::Unknown),
+    ));
+}
+
+/// Appends the run to the benchmark history and, if requested, compares it to a baseline.
+/// Returns whether any part regressed.
+fn update_history(timings: &[Timings], options: &Options) -> bool {
+    let entries = benchmark_history::entries_from_timings(
+        timings,
+        options.save_baseline.as_deref(),
+        options.release,
+    );
+
+    let mut has_regressions = false;
+
+    if options.compare {
+        let baseline = options
+            .baseline
+            .clone()
+            .map_or(Baseline::Last, Baseline::Named);
+
+        let history = match benchmark_history::load(options.year) {
+            Ok(history) => history,
+            Err(e) => {
+                eprintln!("Failed to read benchmark history: {e:?}");
+                process::exit(1);
+            }
+        };
+
+        let threshold = options.threshold.unwrap_or(DEFAULT_REGRESSION_THRESHOLD);
+        match benchmark_history::compare(&history, &entries, &baseline, threshold) {
+            Ok(comparisons) => {
+                print_comparisons(&comparisons, &baseline, options.format);
+                has_regressions = comparisons.iter().any(|c| c.regressed);
+            }
+            Err(benchmark_history::Error::UnknownBaseline(name)) => {
+                eprintln!(
+                    "No timings saved under baseline \"{name}\" for {} in this build profile.",
+                    options.year
+                );
+                process::exit(1);
+            }
+            Err(e) => {
+                eprintln!("Failed to compare against benchmark history: {e:?}");
+                process::exit(1);
+            }
+        }
+    }
+
+    if let Err(e) = benchmark_history::append(options.year, &entries) {
+        eprintln!("Failed to write benchmark history: {e:?}");
+    }
+
+    has_regressions
+}
+
+fn print_comparisons(comparisons: &[Comparison], baseline: &Baseline, format: OutputFormat) {
+    // the report is printed to stderr in JSON mode to keep stdout machine-readable.
+    let print = |line: String| match format {
+        OutputFormat::Text => println!("{line}"),
+        OutputFormat::Json => eprintln!("{line}"),
+    };
+
+    let against = match baseline {
+        Baseline::Last => "last run".to_string(),
+        Baseline::Named(name) => format!("baseline \"{name}\""),
+    };
+
+    print(String::new());
+    print(format!(
+        "{ANSI_BOLD}Comparison against {against}:{ANSI_RESET}"
+    ));
+
+    if comparisons.is_empty() {
+        print("No previous timings to compare against.".into());
+    }
+
+    for c in comparisons {
+        let verdict = if c.regressed { " ✖ regression" } else { "" };
+        print(format!(
+            "Day {} Part {}: {:.1?} → {:.1?} ({:+.1}%){verdict}",
+            c.day, c.part, c.baseline, c.current, c.change
+        ));
     }
 }
 
@@ -56,45 +549,128 @@ impl From<std::io::Error> for Error {
     }
 }
 
-#[must_use]
-pub fn get_path_for_bin(day: Day) -> String {
-    format!("./src/bin/{day}.rs")
+/// Solutions are compiled into this binary as well, see [`registry`](crate::template::registry).
+/// This module runs them in-process, which avoids spawning one process per day.
+mod in_process {
+    use super::Options;
+    use crate::template::record::{OutputFormat, PartRecord};
+    use crate::template::{profiles, registry, runner};
+    use crate::Day;
+    use std::time::Duration;
+
+    /// Runs the solution of a day against the input of a profile and returns the records of its parts.
+    pub fn run_day(day: Day, options: &Options, profile: &str) -> Vec<PartRecord> {
+        let Some(solution) = registry::get(options.year, day) else {
+            return vec![];
+        };
+
+        let input = match profiles::read_input(options.year, profile, day) {
+            Ok(input) => input,
+            Err(e) => {
+                eprintln!("Could not read the input of day {day}: {e}");
+                return vec![];
+            }
+        };
+
+        let budget = options.time.then(|| {
+            options
+                .bench_time
+                .map_or(runner::DEFAULT_BENCH_BUDGET, Duration::from_millis)
+        });
+
+        (solution.run)(&input, budget)
+    }
+
+    pub fn print_records(records: &[PartRecord], format: OutputFormat) {
+        for record in records {
+            match format {
+                OutputFormat::Text => runner::print_record(record),
+                OutputFormat::Json => println!("{}", record.to_json()),
+            }
+        }
+    }
 }
 
-/// All solutions live in isolated binaries.
-/// This module encapsulates interaction with these binaries, both invoking them as well as parsing the timing output.
+/// With `--isolated`, solutions run in their own binaries.
+/// This module encapsulates interaction with these binaries, both invoking them as well as collecting the records they emit.
 mod child_commands {
-    use super::{get_path_for_bin, Error};
+    use super::{Error, Options};
+    use crate::template::record::{OutputFormat, PartRecord};
+    use crate::template::{get_path_for_bin, profiles, runner};
     use crate::Day;
     use std::{
+        env,
         io::{BufRead, BufReader},
-        path::Path,
+        path::{Path, PathBuf},
         process::{Command, Stdio},
         thread,
     };
 
-    /// Run the solution bin for a given day
-    pub fn run_solution(day: Day, is_timed: bool, is_release: bool) -> Result<Vec<String>, Error> {
+    /// Arguments passed to solution binaries.
+    fn solution_args(day: Day, options: &Options, profile: &str) -> Vec<String> {
+        // children always report in JSON, `all` renders the records itself.
+        let mut args = vec!["--format".to_string(), "json".to_string()];
+
+        if options.time {
+            // mirror `--time` flag to child invocations.
+            args.push("--time".into());
+        }
+
+        if let Some(bench_time) = options.bench_time {
+            args.push("--bench-time".into());
+            args.push(bench_time.to_string());
+        }
+
+        if profile != profiles::DEFAULT {
+            args.push("--input".into());
+            args.push(profiles::get_input_path(options.year, profile, day));
+        }
+
+        args
+    }
+
+    /// Prints a line of solution output, rendering records in the requested format.
+    fn render_line(line: &str, format: OutputFormat, records: &mut Vec<PartRecord>) {
+        match PartRecord::from_json(line) {
+            Some(record) => {
+                match format {
+                    OutputFormat::Text => runner::print_record(&record),
+                    OutputFormat::Json => println!("{line}"),
+                }
+                records.push(record);
+            }
+            None => println!("{line}"),
+        }
+    }
+
+    /// Run the solution bin for a given day and collect the part records it emits.
+    /// Records are printed in the requested format as they arrive, any other output is forwarded as-is.
+    pub fn run_solution(
+        day: Day,
+        options: &Options,
+        profile: &str,
+    ) -> Result<Vec<PartRecord>, Error> {
         // skip command invocation for days that have not been scaffolded yet.
-        if !Path::new(&get_path_for_bin(day)).exists() {
+        if !Path::new(&get_path_for_bin(options.year, day)).exists() {
             return Ok(vec![]);
         }
 
-        let day_padded = day.to_string();
-        let mut args = vec!["run", "--quiet", "--bin", &day_padded];
+        let mut args = vec![
+            "run".to_string(),
+            "--quiet".into(),
+            "--bin".into(),
+            format!("{}-{day}", options.year),
+        ];
 
-        if is_release {
-            args.push("--release");
+        if options.release {
+            args.push("--release".into());
         }
 
-        if is_timed {
-            // mirror `--time` flag to child invocations.
-            args.push("--");
-            args.push("--time");
-        }
+        args.push("--".into());
+        args.extend(solution_args(day, options, profile));
 
         // spawn child command with piped stdout/stderr.
-        // forward output to stdout/stderr while grabbing stdout lines.
+        // forward output to stdout/stderr while grabbing records from stdout.
 
         let mut cmd = Command::new("cargo")
             .args(&args)
@@ -105,7 +681,7 @@ mod child_commands {
         let stdout = BufReader::new(cmd.stdout.take().ok_or(super::Error::BrokenPipe)?);
         let stderr = BufReader::new(cmd.stderr.take().ok_or(super::Error::BrokenPipe)?);
 
-        let mut output = vec![];
+        let mut records = vec![];
 
         let thread = thread::spawn(move || {
             stderr.lines().for_each(|line| {
@@ -114,78 +690,120 @@ mod child_commands {
         });
 
         for line in stdout.lines() {
-            let line = line.unwrap();
-            println!("{line}");
-            output.push(line);
+            render_line(&line.unwrap(), options.format, &mut records);
         }
 
         thread.join().unwrap();
         cmd.wait()?;
 
-        Ok(output)
+        Ok(records)
     }
 
-    pub fn parse_exec_time(output: &[String], day: Day) -> super::Timings {
+    /// Buffered output of a solution binary.
+    #[derive(Default)]
+    pub struct Captured {
+        stdout: String,
+        stderr: String,
+    }
+
+    impl Captured {
+        /// Prints the buffered output and returns the records it contains.
+        pub fn replay(&self, format: OutputFormat) -> Vec<PartRecord> {
+            let mut records = vec![];
+
+            for line in self.stdout.lines() {
+                render_line(line, format, &mut records);
+            }
+
+            eprint!("{}", self.stderr);
+            records
+        }
+    }
+
+    /// Builds all solution binaries once.
+    pub fn build_solutions(options: &Options) -> Result<(), Error> {
+        let mut args = vec!["build", "--quiet", "--bins"];
+
+        if options.release {
+            args.push("--release");
+        }
+
+        let status = Command::new("cargo").args(&args).status()?;
+
+        if status.success() {
+            Ok(())
+        } else {
+            Err(Error::Parser("could not build solutions.".into()))
+        }
+    }
+
+    fn get_path_for_executable(day: Day, options: &Options) -> PathBuf {
+        let target_dir =
+            env::var_os("CARGO_TARGET_DIR").map_or_else(|| "target".into(), PathBuf::from);
+        let profile = if options.release { "release" } else { "debug" };
+        target_dir
+            .join(profile)
+            .join(format!("{}-{day}{}", options.year, env::consts::EXE_SUFFIX))
+    }
+
+    /// Run the prebuilt solution binary for a given day, buffering its output.
+    pub fn run_executable(day: Day, options: &Options, profile: &str) -> Captured {
+        // skip command invocation for days that have not been scaffolded yet.
+        if !Path::new(&get_path_for_bin(options.year, day)).exists() {
+            return Captured::default();
+        }
+
+        match Command::new(get_path_for_executable(day, options))
+            .args(solution_args(day, options, profile))
+            .output()
+        {
+            Ok(output) => Captured {
+                stdout: String::from_utf8_lossy(&output.stdout).into(),
+                stderr: String::from_utf8_lossy(&output.stderr).into(),
+            },
+            Err(e) => Captured {
+                stdout: String::new(),
+                stderr: format!("Failed to run day {day}: {e}\n"),
+            },
+        }
+    }
+
+    pub fn timings_from_records(records: &[PartRecord], day: Day) -> super::Timings {
         let mut timings = super::Timings {
             day,
+            parse: None,
             part_1: None,
             part_2: None,
+            part_1_stats: None,
+            part_2_stats: None,
             total_nanos: 0_f64,
         };
 
-        output
-            .iter()
-            .filter_map(|l| {
-                if !l.contains(" samples)") {
-                    return None;
-                }
+        // the parse step is shared, so it is counted once.
+        if let Some(duration) = records.iter().find_map(|r| r.parse_duration) {
+            timings.parse = Some(format!("{duration:.1?}"));
+            #[allow(clippy::cast_precision_loss)]
+            let nanos = duration.as_nanos() as f64;
+            timings.total_nanos += nanos;
+        }
 
-                let Some((timing_str, nanos)) = parse_time(l) else {
-                    eprintln!("Could not parse timings from line: {l}");
-                    return None;
-                };
+        for record in records.iter().filter(|r| r.answer.is_some()) {
+            let timing_str = Some(format!("{:.1?}", record.duration));
 
-                let part = l.split(':').next()?;
-                Some((part, timing_str, nanos))
-            })
-            .for_each(|(part, timing_str, nanos)| {
-                if part.contains("Part 1") {
-                    timings.part_1 = Some(timing_str.into());
-                } else if part.contains("Part 2") {
-                    timings.part_2 = Some(timing_str.into());
-                }
+            match record.part {
+                1 => (timings.part_1, timings.part_1_stats) = (timing_str, record.stats),
+                2 => (timings.part_2, timings.part_2_stats) = (timing_str, record.stats),
+                _ => continue,
+            }
 
-                timings.total_nanos += nanos;
-            });
+            #[allow(clippy::cast_precision_loss)]
+            let nanos = record.duration.as_nanos() as f64;
+            timings.total_nanos += nanos;
+        }
 
         timings
     }
 
-    fn parse_to_float(s: &str, postfix: &str) -> Option<f64> {
-        s.split(postfix).next()?.parse().ok()
-    }
-
-    fn parse_time(line: &str) -> Option<(&str, f64)> {
-        // for possible time formats, see: https://github.com/rust-lang/rust/blob/1.64.0/library/core/src/time.rs#L1176-L1200
-        let str_timing = line
-            .split(" samples)")
-            .next()?
-            .split('(')
-            .last()?
-            .split('@')
-            .next()?
-            .trim();
-
-        let parsed_timing = match str_timing {
-            s if s.contains("ns") => s.split("ns").next()?.parse::<f64>().ok(),
-            s if s.contains("µs") => parse_to_float(s, "µs").map(|x| x * 1000_f64),
-            s if s.contains("ms") => parse_to_float(s, "ms").map(|x| x * 1_000_000_f64),
-            s => parse_to_float(s, "s").map(|x| x * 1_000_000_000_f64),
-        }?;
-
-        Some((str_timing, parsed_timing))
-    }
-
     /// copied from: https://github.com/rust-lang/rust/blob/1.64.0/library/std/src/macros.rs#L328-L333
     #[cfg(feature = "test_lib")]
     macro_rules! assert_approx_eq {
@@ -202,53 +820,115 @@ mod child_commands {
 
     #[cfg(feature = "test_lib")]
     mod tests {
-        use super::parse_exec_time;
-
-        use crate::day;
+        use std::time::Duration;
+
+        use super::timings_from_records;
+
+        use crate::template::record::{PartRecord, Status};
+        use crate::{day, year};
+
+        fn record(part: u8, answer: Option<&str>, nanos: u64) -> PartRecord {
+            PartRecord {
+                year: year!(2023),
+                day: day!(1),
+                part,
+                answer: answer.map(Into::into),
+                duration: Duration::from_nanos(nanos),
+                samples: 1,
+                stats: None,
+                status: if answer.is_some() {
+                    Status::Solved
+                } else {
+                    Status::Unsolved
+                },
+                error: None,
+                parse_duration: None,
+            }
+        }
 
         #[test]
         fn test_well_formed() {
-            let res = parse_exec_time(
-                &[
-                    "Part 1: 0 (74.13ns @ 100000 samples)".into(),
-                    "Part 2: 10 (74.13ms @ 99999 samples)".into(),
-                    "".into(),
-                ],
+            let res = timings_from_records(
+                &[record(1, Some("0"), 74), record(2, Some("10"), 74_130_000)],
                 day!(1),
             );
-            assert_approx_eq!(res.total_nanos, 74130074.13_f64);
-            assert_eq!(res.part_1.unwrap(), "74.13ns");
-            assert_eq!(res.part_2.unwrap(), "74.13ms");
+            assert_approx_eq!(res.total_nanos, 74130074_f64);
+            assert_eq!(res.part_1.unwrap(), "74.0ns");
+            assert_eq!(res.part_2.unwrap(), "74.1ms");
         }
 
         #[test]
-        fn test_patterns_in_input() {
-            let res = parse_exec_time(
+        fn test_answers_with_patterns() {
+            let res = timings_from_records(
                 &[
-                    "Part 1: @ @ @ ( ) ms (2s @ 5 samples)".into(),
-                    "Part 2: 10s (100ms @ 1 samples)".into(),
-                    "".into(),
+                    record(1, Some("@ @ @ ( ) ms samples)"), 2_000_000_000),
+                    record(2, Some("10s"), 100_000_000),
                 ],
                 day!(1),
             );
             assert_approx_eq!(res.total_nanos, 2100000000_f64);
-            assert_eq!(res.part_1.unwrap(), "2s");
-            assert_eq!(res.part_2.unwrap(), "100ms");
+            assert_eq!(res.part_1.unwrap(), "2.0s");
+            assert_eq!(res.part_2.unwrap(), "100.0ms");
         }
 
         #[test]
         fn test_missing_parts() {
-            let res = parse_exec_time(
-                &[
-                    "Part 1: ✖        ".into(),
-                    "Part 2: ✖        ".into(),
-                    "".into(),
-                ],
-                day!(1),
-            );
+            let res = timings_from_records(&[record(1, None, 10), record(2, None, 10)], day!(1));
             assert_approx_eq!(res.total_nanos, 0_f64);
             assert_eq!(res.part_1.is_none(), true);
             assert_eq!(res.part_2.is_none(), true);
         }
+
+        #[test]
+        fn test_parse_step() {
+            let parsed = |part, answer, nanos| PartRecord {
+                parse_duration: Some(Duration::from_nanos(1_500)),
+                ..record(part, answer, nanos)
+            };
+            let res = timings_from_records(
+                &[parsed(1, Some("0"), 100), parsed(2, Some("1"), 200)],
+                day!(1),
+            );
+            assert_approx_eq!(res.total_nanos, 1800_f64);
+            assert_eq!(res.parse.unwrap(), "1.5µs");
+            assert_eq!(res.part_1.unwrap(), "100.0ns");
+        }
+    }
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::Results;
+    use crate::day;
+    use crate::template::answers::Check;
+
+    #[test]
+    fn counts_checked_parts() {
+        let unchecked = Results {
+            checks: vec![(day!(1), [Check::Unknown, Check::Unknown])],
+            ..Results::default()
+        };
+        assert_eq!(unchecked.checked_parts(), (0, 2));
+        assert!(!unchecked.has_failures());
+        assert_eq!(Results::default().checked_parts(), (0, 0));
+
+        let partial = Results {
+            checks: vec![
+                (day!(1), [Check::Pass, Check::Unknown]),
+                (
+                    day!(2),
+                    [
+                        Check::Fail {
+                            expected: "1".into(),
+                            actual: None,
+                        },
+                        Check::Pass,
+                    ],
+                ),
+            ],
+            ..Results::default()
+        };
+        assert_eq!(partial.checked_parts(), (3, 1));
+        assert!(partial.has_failures());
     }
 }
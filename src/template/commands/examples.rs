@@ -0,0 +1,108 @@
+use std::{fs, path::Path, process};
+
+use crate::template::examples::{self, Example};
+use crate::template::get_path_for_bin;
+use crate::{Day, Year};
+
+pub fn handle(year: Year, day: Day, force: bool) {
+    let puzzle_path = format!("data/{year}/puzzles/{day}.md");
+
+    let Ok(puzzle) = fs::read_to_string(&puzzle_path) else {
+        eprintln!(
+            "Could not read the puzzle description \"{puzzle_path}\", run `cargo download {day}` first."
+        );
+        process::exit(1);
+    };
+
+    let examples = examples::extract(&puzzle);
+
+    for (part, example) in [(1, &examples.part_one), (2, &examples.part_two)] {
+        if let Some(input) = &example.input {
+            let path = if part == 1 {
+                format!("data/{year}/examples/{day}.txt")
+            } else {
+                format!("data/{year}/examples/{day}-2.txt")
+            };
+            write_example(&path, input, part, force);
+        }
+    }
+
+    if examples.part_one.input.is_none() {
+        println!("Could not find an example input in the puzzle description.");
+    }
+
+    fill_in_tests(year, day, &examples);
+}
+
+/// Writes an example input, refusing to overwrite a different, non-empty example unless `force` is set.
+pub fn write_example(path: &str, input: &str, part: u8, force: bool) {
+    let existing = fs::read_to_string(path).unwrap_or_default();
+
+    if existing.trim_end() == input.trim_end() {
+        println!("Example of part {part} in \"{path}\" is up to date.");
+        return;
+    }
+
+    if !existing.trim().is_empty() && !force {
+        println!("Not overwriting the example of part {part} in \"{path}\", pass `--force` to replace it.");
+        return;
+    }
+
+    if let Some(dir) = Path::new(path).parent() {
+        let _ = fs::create_dir_all(dir);
+    }
+
+    match fs::write(path, input) {
+        Ok(()) => println!("Wrote example of part {part} to \"{path}\"."),
+        Err(e) => {
+            eprintln!("Failed to write example file: {e}");
+            process::exit(1);
+        }
+    }
+}
+
+/// Fills in the expected answers in the scaffolded tests of the solution.
+pub fn fill_in_tests(year: Year, day: Day, examples: &examples::Examples) {
+    let module_path = get_path_for_bin(year, day);
+
+    let Ok(mut module) = fs::read_to_string(&module_path) else {
+        println!("No solution at \"{module_path}\", not filling in expected answers.");
+        return;
+    };
+
+    let mut changed = false;
+
+    for (part, example) in [(1, &examples.part_one), (2, &examples.part_two)] {
+        let Example {
+            answer: Some(answer),
+            input,
+        } = example
+        else {
+            if part == 1 || examples.part_two != Example::default() {
+                println!("Could not find the expected answer of part {part}.");
+            }
+            continue;
+        };
+
+        match examples::fill_in_test(&module, part, answer, part == 2 && input.is_some()) {
+            Some(filled) => {
+                module = filled;
+                changed = true;
+                println!("Expecting {answer} for the example of part {part}.");
+            }
+            None => {
+                println!(
+                    "Not changing the test of part {part}, it already has an expected answer."
+                );
+            }
+        }
+    }
+
+    if changed {
+        if let Err(e) = fs::write(&module_path, module) {
+            eprintln!("Failed to write module file: {e}");
+            process::exit(1);
+        }
+        println!("Updated the tests in \"{module_path}\".");
+    }
+}
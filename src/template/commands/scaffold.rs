@@ -1,18 +1,30 @@
 use std::{
-    fs::{File, OpenOptions},
-    io::Write,
+    borrow::Cow,
+    fs::{self, File, OpenOptions},
+    io::{self, Write},
+    path::Path,
     process,
 };
 
-use crate::Day;
+use crate::template::examples::{self, Examples};
+use crate::template::{aoc_client, commands, get_path_for_bin, readme_benchmarks};
+use crate::{Day, Year};
 
-const MODULE_TEMPLATE: &str = r#"advent_of_code::solution!(DAY_NUMBER);
+/// Built-in module template, used unless a template in [`TEMPLATE_DIR`] is selected.
+/// Templates may contain these placeholders:
+/// - `YEAR_NUMBER` and `DAY_NUMBER`, e.g. `2023` and `3`.
+/// - `ANSWER_TYPE`, the answer type selected with `--type`.
+/// - `PUZZLE_TITLE`, e.g. `Gear Ratios`, or `Day 3` if the puzzle has not been downloaded.
+/// - `PART_ONE_EXPECTED` and `PART_TWO_EXPECTED`, the expected results for the examples of
+///   the downloaded puzzle, e.g. `Some(4361)`, or `None` if they are not known yet.
+const MODULE_TEMPLATE: &str = r#"//! [PUZZLE_TITLE](https://adventofcode.com/YEAR_NUMBER/day/DAY_NUMBER)
+advent_of_code::solution!(YEAR_NUMBER, DAY_NUMBER);
 
-pub fn part_one(input: &str) -> Option<u32> {
+pub fn part_one(input: &str) -> Option<ANSWER_TYPE> {
     None
 }
 
-pub fn part_two(input: &str) -> Option<u32> {
+pub fn part_two(input: &str) -> Option<ANSWER_TYPE> {
     None
 }
 
@@ -22,73 +34,332 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, None);
+        let result = part_one(&advent_of_code::template::read_file("examples", YEAR, DAY));
+        assert_eq!(result, PART_ONE_EXPECTED);
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, None);
+        let result = part_two(&advent_of_code::template::read_file("examples", YEAR, DAY));
+        assert_eq!(result, PART_TWO_EXPECTED);
     }
 }
 "#;
 
+/// Answer type of scaffolded solutions if no `--type` is passed.
+/// Any type that implements `Display` works, e.g. `i64` or `String`.
+pub const DEFAULT_ANSWER_TYPE: &str = "u64";
+
+/// Directory of project-local module templates, selected with `--template <name>`.
+/// `default.rs` in this directory replaces the built-in template.
+pub const TEMPLATE_DIR: &str = ".aoc/templates";
+
 fn safe_create_file(path: &str) -> Result<File, std::io::Error> {
     OpenOptions::new().write(true).create_new(true).open(path)
 }
 
 fn create_file(path: &str) -> Result<File, std::io::Error> {
-    OpenOptions::new().write(true).create(true).open(path)
+    if let Some(dir) = Path::new(path).parent() {
+        fs::create_dir_all(dir)?;
+    }
+
+    OpenOptions::new()
+        .write(true)
+        .create(true)
+        .truncate(false)
+        .open(path)
+}
+
+/// Reads the module template called `name` from [`TEMPLATE_DIR`].
+/// Without a name, `default.rs` is used if it exists, the built-in template otherwise.
+fn load_template(name: Option<&str>) -> Result<Cow<'static, str>, String> {
+    let Some(name) = name else {
+        let path = Path::new(TEMPLATE_DIR).join("default.rs");
+        return Ok(fs::read_to_string(path).map_or(Cow::Borrowed(MODULE_TEMPLATE), Cow::Owned));
+    };
+
+    let path = Path::new(TEMPLATE_DIR).join(format!("{name}.rs"));
+    fs::read_to_string(&path).map(Cow::Owned).map_err(|_| {
+        let available = available_templates();
+        if available.is_empty() {
+            format!("template \"{name}\" not found, there are no templates in \"{TEMPLATE_DIR}\"")
+        } else {
+            format!(
+                "template \"{name}\" not found in \"{TEMPLATE_DIR}\", available templates: {}",
+                available.join(", ")
+            )
+        }
+    })
+}
+
+/// Names of the templates in [`TEMPLATE_DIR`], sorted.
+fn available_templates() -> Vec<String> {
+    let mut names: Vec<String> = fs::read_dir(TEMPLATE_DIR)
+        .map(|entries| {
+            entries
+                .filter_map(Result::ok)
+                .filter_map(|e| Some(e.file_name().to_str()?.strip_suffix(".rs")?.to_string()))
+                .collect()
+        })
+        .unwrap_or_default();
+    names.sort();
+    names
+}
+
+/// Fills in the placeholders of a module template, see [`MODULE_TEMPLATE`].
+/// The puzzle title and example answers are read from the puzzle description, if it has been downloaded.
+/// If part two has an example of its own, its test reads that example instead of the one of part one.
+fn render(template: &str, year: Year, day: Day, answer_type: &str, puzzle: Option<&str>) -> String {
+    let examples = puzzle.map(examples::extract).unwrap_or_default();
+    let title = puzzle
+        .and_then(examples::title)
+        .unwrap_or_else(|| format!("Day {}", day.into_inner()));
+
+    let module = template
+        .replace("YEAR_NUMBER", &year.to_string())
+        .replace("DAY_NUMBER", &day.into_inner().to_string())
+        .replace("ANSWER_TYPE", answer_type)
+        .replace("PUZZLE_TITLE", &title)
+        .replace(
+            "PART_ONE_EXPECTED",
+            &examples::expected(examples.part_one.answer.as_deref(), Some(answer_type)),
+        )
+        .replace(
+            "PART_TWO_EXPECTED",
+            &examples::expected(examples.part_two.answer.as_deref(), Some(answer_type)),
+        );
+
+    if examples.part_two.input.is_some() {
+        examples::use_own_example(&module, 2)
+    } else {
+        module
+    }
+}
+
+/// Options of the `scaffold` command.
+#[derive(Debug, Default)]
+pub struct Options<'a> {
+    /// Answer type of the parts, [`DEFAULT_ANSWER_TYPE`] if not set.
+    pub answer_type: Option<&'a str>,
+    /// Name of the template in [`TEMPLATE_DIR`].
+    pub template: Option<&'a str>,
+    /// Download the input and puzzle description before creating the module.
+    pub download: bool,
 }
 
-pub fn handle(day: Day) {
-    let input_path = format!("data/inputs/{day}.txt");
-    let example_path = format!("data/examples/{day}.txt");
-    let module_path = format!("src/bin/{day}.rs");
+/// Scaffolds a day. Files that exist already are kept, so running this again is safe,
+/// e.g. to fill in the puzzle title and expected answers once the puzzle has been downloaded.
+pub fn handle(year: Year, day: Day, options: &Options) {
+    let input_path = format!("data/{year}/inputs/{day}.txt");
+    let module_path = get_path_for_bin(year, day);
 
-    let mut file = match safe_create_file(&module_path) {
-        Ok(file) => file,
+    let template = match load_template(options.template) {
+        Ok(template) => template,
         Err(e) => {
-            eprintln!("Failed to create module file: {e}");
+            eprintln!("Failed to load template: {e}");
             process::exit(1);
         }
     };
 
-    match file.write_all(
-        MODULE_TEMPLATE
-            .replace("DAY_NUMBER", &day.into_inner().to_string())
-            .as_bytes(),
-    ) {
-        Ok(()) => {
-            println!("Created module file \"{}\"", &module_path);
-        }
-        Err(e) => {
-            eprintln!("Failed to write module contents: {e}");
+    if options.download {
+        if let Err(e) = aoc_client::download_missing(year, day) {
+            eprintln!("Failed to download day {day}: {e}");
             process::exit(1);
         }
     }
 
-    match create_file(&input_path) {
-        Ok(_) => {
-            println!("Created empty input file \"{}\"", &input_path);
+    let puzzle = fs::read_to_string(format!("data/{year}/puzzles/{day}.md")).ok();
+
+    match safe_create_file(&module_path) {
+        Ok(mut file) => {
+            let contents = render(
+                &template,
+                year,
+                day,
+                options.answer_type.unwrap_or(DEFAULT_ANSWER_TYPE),
+                puzzle.as_deref(),
+            );
+
+            match file.write_all(contents.as_bytes()) {
+                Ok(()) => {
+                    println!("Created module file \"{}\"", &module_path);
+                }
+                Err(e) => {
+                    eprintln!("Failed to write module contents: {e}");
+                    process::exit(1);
+                }
+            }
+        }
+        Err(e) if e.kind() == io::ErrorKind::AlreadyExists => {
+            println!("Module file \"{}\" already exists, skipping.", &module_path);
+            if let Some(puzzle) = &puzzle {
+                update_module(year, day, &module_path, puzzle);
+            }
         }
         Err(e) => {
-            eprintln!("Failed to create input file: {e}");
+            eprintln!("Failed to create module file: {e}");
             process::exit(1);
         }
     }
 
-    match create_file(&example_path) {
+    create_empty_file(&input_path, "input");
+
+    write_examples(
+        &format!("data/{year}/examples"),
+        day,
+        &puzzle.as_deref().map(examples::extract).unwrap_or_default(),
+    );
+
+    match readme_benchmarks::add_row(year, day) {
+        Ok(true) => println!("Added day {day} to the benchmark table in the README."),
+        Ok(false) => {}
+        Err(e) => eprintln!("Failed to add day {day} to the benchmark table in the README: {e:?}"),
+    }
+
+    println!("---");
+    println!(
+        "🎄 Type `cargo solve {}{}` to run your solution.",
+        day,
+        year_arg(year)
+    );
+}
+
+/// Writes the example inputs of a puzzle to `dir`, e.g. `01.txt`, and `01-2.txt` if part two has an example of its own.
+/// Without an example of part one, an empty example file is created.
+fn write_examples(dir: &str, day: Day, examples: &Examples) {
+    let example_path = format!("{dir}/{day}.txt");
+
+    match &examples.part_one.input {
+        Some(input) => commands::examples::write_example(&example_path, input, 1, false),
+        None => create_empty_file(&example_path, "example"),
+    }
+
+    if let Some(input) = &examples.part_two.input {
+        commands::examples::write_example(&format!("{dir}/{day}-2.txt"), input, 2, false);
+    }
+}
+
+/// Creates an empty file unless it exists already.
+fn create_empty_file(path: &str, kind: &str) {
+    if Path::new(path).exists() {
+        return;
+    }
+
+    match create_file(path) {
         Ok(_) => {
-            println!("Created empty example file \"{}\"", &example_path);
+            println!("Created empty {kind} file \"{path}\"");
         }
         Err(e) => {
-            eprintln!("Failed to create example file: {e}");
+            eprintln!("Failed to create {kind} file: {e}");
             process::exit(1);
         }
     }
+}
 
-    println!("---");
-    println!("🎄 Type `cargo solve {}` to run your solution.", day);
+/// Fills in the puzzle title and the expected answers of an existing module, where they are still placeholders.
+fn update_module(year: Year, day: Day, module_path: &str, puzzle: &str) {
+    if let Some(title) = examples::title(puzzle) {
+        let placeholder = title_link(year, day, &format!("Day {}", day.into_inner()));
+        if let Ok(module) = fs::read_to_string(module_path) {
+            if module.contains(&placeholder) {
+                let module = module.replacen(&placeholder, &title_link(year, day, &title), 1);
+                match fs::write(module_path, module) {
+                    Ok(()) => println!("Added the puzzle title \"{title}\" to \"{module_path}\"."),
+                    Err(e) => eprintln!("Failed to write module file: {e}"),
+                }
+            }
+        }
+    }
+
+    commands::examples::fill_in_tests(year, day, &examples::extract(puzzle));
+}
+
+/// Link to the puzzle of a day, as used in the doc comment of the built-in template.
+fn title_link(year: Year, day: Day, title: &str) -> String {
+    format!(
+        "[{title}](https://adventofcode.com/{year}/day/{})",
+        day.into_inner()
+    )
+}
+
+/// The `--year` argument needed to select `year`, empty if it is the configured default year.
+fn year_arg(year: Year) -> String {
+    if Year::from_env() == Some(year) {
+        String::new()
+    } else {
+        format!(" --year {year}")
+    }
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::{env, fs};
+
+    use super::{render, write_examples, MODULE_TEMPLATE};
+    use crate::template::examples;
+    use crate::{day, year};
+
+    const TWO_EXAMPLES: &str = "## --- Day 1: Trebuchet?! ---\n\n```\n1abc2\n```\n\nAdding these together produces *`142`*.\n\n## --- Part Two ---\n\n```\ntwo1nine\n```\n\nAdding these together produces *`29`*.\n";
+
+    #[test]
+    fn renders_placeholders() {
+        let puzzle = "## --- Day 3: Gear Ratios ---\n\nFor example:\n\n```\n467..114..\n```\n\nTheir sum is *`4361`*.\n";
+
+        let module = render(MODULE_TEMPLATE, year!(2023), day!(3), "u32", Some(puzzle));
+        assert!(module.starts_with(
+            "//! [Gear Ratios](https://adventofcode.com/2023/day/3)\nadvent_of_code::solution!(2023, 3);"
+        ));
+        assert!(module.contains("pub fn part_one(input: &str) -> Option<u32> {"));
+        assert!(module.contains("assert_eq!(result, Some(4361));"));
+        assert!(module.contains("assert_eq!(result, None);"));
+
+        let module = render(
+            MODULE_TEMPLATE,
+            year!(2023),
+            day!(3),
+            "String",
+            Some(puzzle),
+        );
+        assert!(module.contains(r#"assert_eq!(result, Some("4361".to_string()));"#));
+
+        let module = render(
+            MODULE_TEMPLATE,
+            year!(2023),
+            day!(1),
+            "u32",
+            Some(TWO_EXAMPLES),
+        );
+        assert!(module
+            .contains(r#"part_one(&advent_of_code::template::read_file("examples", YEAR, DAY));"#));
+        assert!(module.contains(
+            r#"part_two(&advent_of_code::template::read_file_part("examples", YEAR, DAY, 2));"#
+        ));
+        assert!(module.contains("assert_eq!(result, Some(29));"));
+
+        let module = render("// PUZZLE_TITLE", year!(2023), day!(3), "u32", None);
+        assert_eq!(module, "// Day 3");
+    }
+
+    #[test]
+    fn writes_own_example_of_part_two() {
+        let dir = env::temp_dir().join(format!("aoc-scaffold-{}", std::process::id()));
+        let dir = dir.to_str().unwrap();
+
+        write_examples(dir, day!(1), &examples::extract(TWO_EXAMPLES));
+        let module = render(
+            MODULE_TEMPLATE,
+            year!(2023),
+            day!(1),
+            "u32",
+            Some(TWO_EXAMPLES),
+        );
+
+        let part_one = fs::read_to_string(format!("{dir}/01.txt"));
+        let part_two = fs::read_to_string(format!("{dir}/01-2.txt"));
+        fs::remove_dir_all(dir).unwrap();
+
+        assert_eq!(part_one.unwrap().trim_end(), "1abc2");
+        assert_eq!(part_two.unwrap().trim_end(), "two1nine");
+        assert!(module.contains(r#"read_file_part("examples", YEAR, DAY, 2)"#));
+    }
 }
@@ -1,31 +1,412 @@
 use std::process::{Command, Stdio};
 
-use crate::Day;
+use crate::template::record::OutputFormat;
+use crate::{Day, Year};
 
-pub fn handle(day: Day, release: bool, time: bool, submit_part: Option<u8>) {
-    let mut cmd_args = vec!["run".to_string(), "--bin".to_string(), day.to_string()];
+/// Options of the `solve` command.
+#[derive(Debug, Clone)]
+pub struct Options {
+    pub year: Year,
+    pub day: Day,
+    pub release: bool,
+    pub time: bool,
+    pub submit: Option<u8>,
+    pub format: OutputFormat,
+    pub bench_time: Option<u64>,
+    /// Path of an input to run the solution against instead of the puzzle input, `-` for stdin.
+    pub input: Option<String>,
+    /// Run the solution against the example of the day.
+    pub example: bool,
+    /// Rerun the solution whenever its sources or data change.
+    pub watch: bool,
+    /// In watch mode, also run the example tests of the day.
+    pub test: bool,
+}
+
+pub fn handle(options: &Options) {
+    if options.watch {
+        watch::run(options);
+        return;
+    }
+
+    let mut cmd = Command::new("cargo")
+        .args(cargo_args(options, options.format))
+        .stdout(Stdio::inherit())
+        .stderr(Stdio::inherit())
+        .spawn()
+        .unwrap();
+
+    cmd.wait().unwrap();
+}
+
+/// Arguments of the `cargo run` invocation of the solution.
+fn cargo_args(options: &Options, format: OutputFormat) -> Vec<String> {
+    let mut cmd_args = vec![
+        "run".to_string(),
+        "--bin".to_string(),
+        format!("{}-{}", options.year, options.day),
+    ];
 
-    if release {
+    if options.release {
         cmd_args.push("--release".to_string());
     }
 
     cmd_args.push("--".to_string());
 
-    if let Some(submit_part) = submit_part {
+    if let Some(submit_part) = options.submit {
         cmd_args.push("--submit".to_string());
         cmd_args.push(submit_part.to_string());
     }
 
-    if time {
+    if options.time {
         cmd_args.push("--time".to_string());
     }
 
-    let mut cmd = Command::new("cargo")
-        .args(&cmd_args)
-        .stdout(Stdio::inherit())
-        .stderr(Stdio::inherit())
-        .spawn()
-        .unwrap();
+    if let Some(bench_time) = options.bench_time {
+        cmd_args.push("--bench-time".to_string());
+        cmd_args.push(bench_time.to_string());
+    }
 
-    cmd.wait().unwrap();
+    if let Some(input) = &options.input {
+        cmd_args.push("--input".to_string());
+        cmd_args.push(input.clone());
+    }
+
+    if options.example {
+        cmd_args.push("--example".to_string());
+    }
+
+    if format != OutputFormat::Text {
+        cmd_args.push("--format".to_string());
+        cmd_args.push(format.to_string());
+    }
+
+    cmd_args
+}
+
+/// With `--watch`, the solution is rerun whenever one of its sources or data files changes.
+/// Files are polled for changes, which is fast enough for a handful of files and needs no platform support.
+mod watch {
+    use super::{cargo_args, Options};
+    use crate::template::record::{OutputFormat, PartRecord};
+    use crate::template::{runner, ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
+    use crate::{Day, Year};
+    use std::{
+        collections::BTreeMap,
+        fs,
+        io::{BufRead, BufReader},
+        path::{Path, PathBuf},
+        process::{self, Command, Stdio},
+        thread,
+        time::{Duration, SystemTime},
+    };
+
+    const POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+    /// Modification times of the watched files.
+    type Snapshot = BTreeMap<PathBuf, SystemTime>;
+
+    pub fn run(options: &Options) {
+        if options.input.as_deref() == Some("-") {
+            eprintln!("Cannot read the input from stdin in watch mode, pass a file to `--input`.");
+            process::exit(1);
+        }
+
+        if options.submit.is_some() {
+            eprintln!("Warning: `--submit` is ignored in watch mode.");
+        }
+
+        let options = Options {
+            submit: None,
+            ..options.clone()
+        };
+
+        let mut snapshot = Snapshot::new();
+        let mut previous: Option<Vec<PartRecord>> = None;
+
+        loop {
+            let current = take_snapshot(&options);
+
+            if current != snapshot {
+                // clear the screen and move the cursor to the top.
+                print!("\x1b[2J\x1b[H");
+                println!(
+                    "{ANSI_BOLD}Watching day {} of {}{ANSI_RESET} {ANSI_ITALIC}(press Ctrl-C to stop){ANSI_RESET}",
+                    options.day, options.year
+                );
+                println!();
+
+                let records = run_solution(&options);
+
+                if let Some(previous) = &previous {
+                    print_changes(previous, &records);
+                }
+
+                if options.test {
+                    run_tests(&options);
+                }
+
+                // keep the last answers if the solution did not build.
+                if !records.is_empty() {
+                    previous = Some(records);
+                }
+
+                snapshot = take_snapshot(&options);
+            }
+
+            thread::sleep(POLL_INTERVAL);
+        }
+    }
+
+    /// Runs the solution, printing its output, and returns the records of its parts.
+    fn run_solution(options: &Options) -> Vec<PartRecord> {
+        let mut args = cargo_args(options, OutputFormat::Json);
+        args.insert(1, "--quiet".into());
+
+        let child = Command::new("cargo")
+            .args(&args)
+            .stdout(Stdio::piped())
+            .stderr(Stdio::inherit())
+            .spawn();
+
+        let mut child = match child {
+            Ok(child) => child,
+            Err(e) => {
+                eprintln!("Failed to run day {}: {e}", options.day);
+                return vec![];
+            }
+        };
+
+        let mut records = vec![];
+
+        if let Some(stdout) = child.stdout.take() {
+            for line in BufReader::new(stdout).lines().map_while(Result::ok) {
+                match PartRecord::from_json(&line) {
+                    Some(record) => {
+                        match options.format {
+                            OutputFormat::Text => runner::print_record(&record),
+                            OutputFormat::Json => println!("{line}"),
+                        }
+                        records.push(record);
+                    }
+                    None => println!("{line}"),
+                }
+            }
+        }
+
+        let _ = child.wait();
+        records
+    }
+
+    /// Runs the example tests of the day.
+    fn run_tests(options: &Options) {
+        println!();
+        println!("{ANSI_BOLD}Example tests:{ANSI_RESET}");
+
+        let status = Command::new("cargo")
+            .args([
+                "test",
+                "--quiet",
+                "--bin",
+                &format!("{}-{}", options.year, options.day),
+            ])
+            .status();
+
+        if let Err(e) = status {
+            eprintln!("Failed to run the example tests: {e}");
+        }
+    }
+
+    /// Prints how the answers changed since the previous run.
+    fn print_changes(previous: &[PartRecord], records: &[PartRecord]) {
+        let changes = answer_changes(previous, records);
+
+        println!();
+        if changes.is_empty() {
+            println!("{ANSI_ITALIC}Answers unchanged since the last run.{ANSI_RESET}");
+        }
+
+        let show = |answer: &Option<String>| answer.clone().unwrap_or_else(|| "✖".into());
+
+        for (part, before, after) in changes {
+            println!(
+                "{ANSI_BOLD}Part {part} changed:{ANSI_RESET} {} → {}",
+                show(&before),
+                show(&after)
+            );
+        }
+    }
+
+    /// Returns the parts whose answer differs between two runs, with the answers before and after.
+    pub(super) fn answer_changes(
+        previous: &[PartRecord],
+        records: &[PartRecord],
+    ) -> Vec<(u8, Option<String>, Option<String>)> {
+        let answer = |records: &[PartRecord], part: u8| {
+            records
+                .iter()
+                .find(|r| r.part == part)
+                .and_then(|r| r.answer.clone())
+        };
+
+        [1, 2]
+            .into_iter()
+            .map(|part| (part, answer(previous, part), answer(records, part)))
+            .filter(|(_, before, after)| before != after)
+            .collect()
+    }
+
+    /// Returns whether a file is watched: the solution of the day, library modules and the data of the day.
+    pub(super) fn is_watched(path: &Path, year: Year, day: Day) -> bool {
+        let Ok(path) = path.strip_prefix(".") else {
+            return false;
+        };
+
+        let file_name = path.file_name().and_then(|n| n.to_str()).unwrap_or("");
+
+        if path.starts_with("src/bin") {
+            file_name == format!("{year}-{day}.rs")
+        } else if path.starts_with("src") {
+            path.extension().is_some_and(|e| e == "rs")
+        } else {
+            let data = Path::new("data").join(year.to_string());
+            (path.starts_with(data.join("inputs")) || path.starts_with(data.join("examples")))
+                && (file_name.starts_with(&format!("{day}."))
+                    || file_name.starts_with(&format!("{day}-")))
+        }
+    }
+
+    fn take_snapshot(options: &Options) -> Snapshot {
+        let (year, day) = (options.year, options.day);
+        let mut snapshot = Snapshot::new();
+
+        // a custom input may live outside of the data directory.
+        if let Some(path) = &options.input {
+            if let Ok(modified) = fs::metadata(path).and_then(|m| m.modified()) {
+                snapshot.insert(PathBuf::from(path), modified);
+            }
+        }
+
+        let data = Path::new(".").join("data").join(year.to_string());
+
+        for dir in [
+            Path::new(".").join("src"),
+            data.join("inputs"),
+            data.join("examples"),
+        ] {
+            collect_files(&dir, &mut |path| {
+                if is_watched(path, year, day) {
+                    if let Ok(modified) = fs::metadata(path).and_then(|m| m.modified()) {
+                        snapshot.insert(path.to_path_buf(), modified);
+                    }
+                }
+            });
+        }
+
+        snapshot
+    }
+
+    fn collect_files(dir: &Path, f: &mut impl FnMut(&Path)) {
+        let Ok(entries) = fs::read_dir(dir) else {
+            return;
+        };
+
+        for entry in entries.filter_map(Result::ok) {
+            let path = entry.path();
+            if path.is_dir() {
+                collect_files(&path, f);
+            } else {
+                f(&path);
+            }
+        }
+    }
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::path::Path;
+    use std::time::Duration;
+
+    use super::watch::{answer_changes, is_watched};
+    use super::{cargo_args, Options};
+    use crate::template::record::{OutputFormat, PartRecord, Status};
+    use crate::{day, year};
+
+    fn record(part: u8, answer: Option<&str>) -> PartRecord {
+        PartRecord {
+            year: year!(2023),
+            day: day!(8),
+            part,
+            answer: answer.map(Into::into),
+            duration: Duration::from_nanos(1),
+            samples: 1,
+            stats: None,
+            status: if answer.is_some() {
+                Status::Solved
+            } else {
+                Status::Unsolved
+            },
+            error: None,
+            parse_duration: None,
+        }
+    }
+
+    #[test]
+    fn finds_answer_changes() {
+        let previous = [record(1, Some("6")), record(2, None)];
+        let current = [record(1, Some("6")), record(2, Some("71503"))];
+        assert_eq!(
+            answer_changes(&previous, &current),
+            [(2, None, Some("71503".into()))]
+        );
+        assert!(answer_changes(&current, &current).is_empty());
+    }
+
+    #[test]
+    fn watches_files_of_the_day() {
+        let watched = |path: &str| is_watched(Path::new(path), year!(2023), day!(8));
+
+        assert!(watched("./src/bin/2023-08.rs"));
+        assert!(!watched("./src/bin/2023-07.rs"));
+        assert!(watched("./src/template/runner.rs"));
+        assert!(watched("./src/utils.rs"));
+        assert!(watched("./data/2023/inputs/08.txt"));
+        assert!(watched("./data/2023/examples/08-2.txt"));
+        assert!(!watched("./data/2023/examples/18.txt"));
+        assert!(!watched("./data/2022/inputs/08.txt"));
+        assert!(!watched("./data/2023/puzzles/08.md"));
+    }
+
+    #[test]
+    fn passes_inputs_to_the_solution() {
+        let options = Options {
+            year: year!(2023),
+            day: day!(5),
+            release: true,
+            time: false,
+            submit: None,
+            format: OutputFormat::Text,
+            bench_time: None,
+            input: Some("-".into()),
+            example: true,
+            watch: false,
+            test: false,
+        };
+
+        assert_eq!(
+            cargo_args(&options, OutputFormat::Json),
+            [
+                "run",
+                "--bin",
+                "2023-05",
+                "--release",
+                "--",
+                "--input",
+                "-",
+                "--example",
+                "--format",
+                "json"
+            ]
+        );
+    }
 }
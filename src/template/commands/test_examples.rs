@@ -0,0 +1,254 @@
+/// Runs the example tests of all scaffolded days and prints a summary.
+/// With the `registry` feature, solutions are compiled into the library (see [`registry`](crate::template::registry)),
+/// so their tests run as part of the library tests, in a single `cargo test` invocation.
+use std::{collections::BTreeMap, fs, path::Path, process};
+
+use crate::template::{get_path_for_bin, ANSI_BOLD, ANSI_RESET};
+use crate::{all_days, Day, Year};
+
+/// Result of an example test of a part.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum TestResult {
+    Passed,
+    Failed,
+    Ignored,
+}
+
+/// Status of a part in the summary.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum PartStatus {
+    Passed,
+    Failed,
+    Ignored,
+    /// The example file of the part is empty or missing.
+    EmptyExample,
+    /// The day has no test for the part.
+    NoTest,
+}
+
+type TestResults = BTreeMap<(Day, u8), TestResult>;
+
+pub fn handle(year: Year) {
+    let days: Vec<Day> = all_days()
+        .filter(|&day| Path::new(&get_path_for_bin(year, day)).exists())
+        .collect();
+
+    if days.is_empty() {
+        println!("No scaffolded days in {year}.");
+        return;
+    }
+
+    let output = match process::Command::new("cargo")
+        .args([
+            "test",
+            "--lib",
+            "--features",
+            "registry",
+            "--",
+            &format!("template::registry::y{year}_"),
+        ])
+        .env("RUST_BACKTRACE", "0")
+        .output()
+    {
+        Ok(output) => output,
+        Err(e) => {
+            eprintln!("Failed to run cargo test: {e}");
+            process::exit(1);
+        }
+    };
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let results = parse_results(&stdout, year);
+
+    // without any results, the tests most likely did not compile.
+    if results.is_empty() && !output.status.success() {
+        eprint!("{}", String::from_utf8_lossy(&output.stderr));
+        eprintln!("Failed to run the example tests.");
+        process::exit(1);
+    }
+
+    let rows: Vec<(Day, [PartStatus; 2])> = days
+        .iter()
+        .map(|&day| {
+            let status = |part| match results.get(&(day, part)) {
+                None => PartStatus::NoTest,
+                Some(TestResult::Failed) => PartStatus::Failed,
+                // passing tests of empty examples mostly assert the scaffolded `None`.
+                Some(_) if is_example_empty(year, day, part) => PartStatus::EmptyExample,
+                Some(TestResult::Passed) => PartStatus::Passed,
+                Some(TestResult::Ignored) => PartStatus::Ignored,
+            };
+            (day, [status(1), status(2)])
+        })
+        .collect();
+
+    print_matrix(&rows);
+
+    // solutions compiled into the library have absolute paths in their panic messages.
+    let root = concat!(env!("CARGO_MANIFEST_DIR"), "/");
+
+    for ((day, part), message) in parse_failures(&stdout, year) {
+        println!();
+        println!("{ANSI_BOLD}Day {day} Part {part} failed:{ANSI_RESET}");
+        println!("{}", message.replace(root, ""));
+    }
+
+    if rows
+        .iter()
+        .flat_map(|(_, s)| s)
+        .any(|s| *s == PartStatus::Failed)
+    {
+        process::exit(1);
+    }
+}
+
+/// Returns whether the example used by a part is empty or missing.
+/// A part-specific example like `01-2.txt` takes precedence over the example of the day.
+fn is_example_empty(year: Year, day: Day, part: u8) -> bool {
+    let part_path = format!("data/{year}/examples/{day}-{part}.txt");
+    let path = if Path::new(&part_path).exists() {
+        part_path
+    } else {
+        format!("data/{year}/examples/{day}.txt")
+    };
+
+    fs::read_to_string(path).map_or(true, |s| s.trim().is_empty())
+}
+
+/// Maps the name of a solution test, e.g. `template::registry::y2023_day01::tests::test_part_one`, to its day and part.
+fn parse_test_name(name: &str, year: Year) -> Option<(Day, u8)> {
+    let rest = name.strip_prefix(&format!("template::registry::y{year}_day"))?;
+    let (day, test) = rest.split_once("::tests::")?;
+    let part = match test {
+        "test_part_one" => 1,
+        "test_part_two" => 2,
+        _ => return None,
+    };
+    Some((day.parse().ok()?, part))
+}
+
+/// Reads the results of the example tests from the output of `cargo test`.
+fn parse_results(output: &str, year: Year) -> TestResults {
+    output
+        .lines()
+        .filter_map(|line| {
+            let (name, result) = line.strip_prefix("test ")?.rsplit_once(" ... ")?;
+            let result = match result {
+                "ok" => TestResult::Passed,
+                "FAILED" => TestResult::Failed,
+                r if r.starts_with("ignored") => TestResult::Ignored,
+                _ => return None,
+            };
+            Some((parse_test_name(name, year)?, result))
+        })
+        .collect()
+}
+
+/// Reads the captured output of failed example tests, e.g. the assertion message.
+fn parse_failures(output: &str, year: Year) -> BTreeMap<(Day, u8), String> {
+    let mut failures = BTreeMap::new();
+    let mut lines = output.lines();
+
+    while let Some(line) = lines.next() {
+        let Some(name) = line
+            .strip_prefix("---- ")
+            .and_then(|l| l.strip_suffix(" stdout ----"))
+        else {
+            continue;
+        };
+
+        let message: Vec<&str> = lines
+            .by_ref()
+            .skip_while(|l| l.is_empty())
+            .take_while(|l| !l.is_empty())
+            .filter(|l| !l.starts_with("note: run with `RUST_BACKTRACE"))
+            .collect();
+
+        if let Some(key) = parse_test_name(name, year) {
+            failures.insert(key, message.join("\n"));
+        }
+    }
+
+    failures
+}
+
+fn print_matrix(rows: &[(Day, [PartStatus; 2])]) {
+    let cell = |status: &PartStatus| match status {
+        PartStatus::Passed => "✔",
+        PartStatus::Failed => "✖",
+        PartStatus::Ignored => "ignored",
+        PartStatus::EmptyExample => "empty example",
+        PartStatus::NoTest => "-",
+    };
+
+    println!("{ANSI_BOLD}Example tests:{ANSI_RESET}");
+    println!("| Day | Part 1 | Part 2 |");
+    println!("| :---: | :---: | :---: |");
+
+    for (day, [part_1, part_2]) in rows {
+        println!("| {day} | {} | {} |", cell(part_1), cell(part_2));
+    }
+
+    let count = |status: PartStatus| {
+        rows.iter()
+            .flat_map(|(_, s)| s)
+            .filter(|s| **s == status)
+            .count()
+    };
+    println!(
+        "{} passed, {} failed, {} with empty examples.",
+        count(PartStatus::Passed),
+        count(PartStatus::Failed),
+        count(PartStatus::EmptyExample),
+    );
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{parse_failures, parse_results, TestResult};
+    use crate::{day, year};
+
+    const OUTPUT: &str = "
+running 4 tests
+test template::registry::y2023_day01::tests::test_part_one ... ok
+test template::registry::y2023_day01::tests::test_part_two ... FAILED
+test template::registry::y2023_day02::tests::test_part_one ... ignored
+test template::registry::y2023_day02::tests::parses_games ... ok
+
+failures:
+
+---- template::registry::y2023_day01::tests::test_part_two stdout ----
+
+thread 'template::registry::y2023_day01::tests::test_part_two' (2961) panicked at src/bin/2023-01.rs:40:9:
+assertion `left == right` failed
+  left: Some(280)
+ right: Some(281)
+note: run with `RUST_BACKTRACE=1` environment variable to display a backtrace
+
+
+failures:
+    template::registry::y2023_day01::tests::test_part_two
+
+test result: FAILED. 2 passed; 1 failed; 1 ignored; 0 measured; 0 filtered out; finished in 0.00s
+";
+
+    #[test]
+    fn parses_test_results() {
+        let results = parse_results(OUTPUT, year!(2023));
+        assert_eq!(results.len(), 3);
+        assert_eq!(results[&(day!(1), 1)], TestResult::Passed);
+        assert_eq!(results[&(day!(1), 2)], TestResult::Failed);
+        assert_eq!(results[&(day!(2), 1)], TestResult::Ignored);
+        assert!(parse_results(OUTPUT, year!(2022)).is_empty());
+    }
+
+    #[test]
+    fn parses_failure_messages() {
+        let failures = parse_failures(OUTPUT, year!(2023));
+        assert_eq!(failures.len(), 1);
+        assert_eq!(
+            failures[&(day!(1), 2)],
+            "thread 'template::registry::y2023_day01::tests::test_part_two' (2961) panicked at src/bin/2023-01.rs:40:9:\nassertion `left == right` failed\n  left: Some(280)\n right: Some(281)"
+        );
+    }
+}
@@ -0,0 +1,414 @@
+//! Extracts example inputs and their expected answers from saved puzzle descriptions.
+//! Handles the markdown written by `cargo read` / `cargo download` as well as the markdown of `aoc-cli`.
+
+/// Example of a part as given in the puzzle description.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Example {
+    /// Example input, [`None`] if the part has no example of its own.
+    /// Part two usually reuses the example of part one.
+    pub input: Option<String>,
+    /// Expected answer for the example.
+    pub answer: Option<String>,
+}
+
+/// Examples of both parts of a puzzle. Part two is empty until part one has been solved.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Examples {
+    pub part_one: Example,
+    pub part_two: Example,
+}
+
+/// Finds the examples in the markdown of a puzzle description.
+#[must_use]
+pub fn extract(markdown: &str) -> Examples {
+    match markdown.find("--- Part Two ---") {
+        Some(pos) => Examples {
+            part_one: extract_section(&markdown[..pos]),
+            part_two: extract_section(&markdown[pos..]),
+        },
+        None => Examples {
+            part_one: extract_section(markdown),
+            part_two: Example::default(),
+        },
+    }
+}
+
+/// Finds the example input and its expected answer in the description of a part.
+fn extract_section(markdown: &str) -> Example {
+    let mut blocks: Vec<(String, bool)> = vec![];
+    let mut prose = String::new();
+    let mut paragraph = "";
+    let mut block: Option<Vec<&str>> = None;
+
+    for line in markdown.lines() {
+        match block.as_mut() {
+            Some(lines) if line.trim_start().starts_with("```") => {
+                while lines.last().is_some_and(|l| l.trim().is_empty()) {
+                    lines.pop();
+                }
+                if !lines.is_empty() {
+                    let input = lines.join("\n") + "\n";
+                    blocks.push((input, paragraph.to_lowercase().contains("example")));
+                }
+                block = None;
+            }
+            Some(lines) => lines.push(line),
+            None if line.trim_start().starts_with("```") => block = Some(vec![]),
+            None => {
+                if !line.trim().is_empty() {
+                    paragraph = line;
+                }
+                prose.push_str(line);
+                prose.push('\n');
+            }
+        }
+    }
+
+    // the example input is usually introduced by a sentence like "For example:".
+    let input = blocks
+        .iter()
+        .find(|(_, introduced)| *introduced)
+        .or_else(|| blocks.first())
+        .map(|(input, _)| input.clone());
+
+    // the answer to the example is usually the last highlighted value before the question.
+    let answer = emphasized_code(&prose).pop();
+
+    Example { input, answer }
+}
+
+/// Returns the emphasized inline code spans of a text, written either as *`x`* or as `*x*`.
+fn emphasized_code(text: &str) -> Vec<String> {
+    let mut spans = vec![];
+    let mut rest = text;
+
+    while let Some(pos) = rest.find(['*', '`']) {
+        rest = &rest[pos..];
+
+        let span = ["*`", "`*"].iter().find_map(|open| {
+            let close = if *open == "*`" { "`*" } else { "*`" };
+            let inner = rest.strip_prefix(open)?;
+            let end = inner.find(close)?;
+            Some((&inner[..end], open.len() + end + close.len()))
+        });
+
+        match span {
+            Some((value, len)) if !value.is_empty() && !value.contains(['`', '*', '\n']) => {
+                spans.push(value.to_string());
+                rest = &rest[len..];
+            }
+            _ => rest = &rest[1..],
+        }
+    }
+
+    spans
+}
+
+/// Fills in the expected answer of a part in the scaffolded test module of a solution.
+/// Only tests that still assert the scaffolded `None` are changed.
+/// If `own_example` is set, the test reads the part-specific example (e.g. `01-2.txt`) instead.
+/// Returns [`None`] if the test could not be found or already has an expected answer.
+#[must_use]
+pub fn fill_in_test(module: &str, part: u8, answer: &str, own_example: bool) -> Option<String> {
+    let (start, end) = find_test(module, part)?;
+    let body = &module[start..end];
+
+    let placeholder = "assert_eq!(result, None);";
+    if !body.contains(placeholder) {
+        return None;
+    }
+
+    let answer_type = answer_type(module, part);
+    let mut body = body.replace(
+        placeholder,
+        &format!(
+            "assert_eq!(result, {});",
+            expected(Some(answer), answer_type)
+        ),
+    );
+    if own_example {
+        body = read_own_example(&body, part);
+    }
+
+    Some(format!("{}{body}{}", &module[..start], &module[end..]))
+}
+
+/// Makes the scaffolded test of a part read the part-specific example (e.g. `01-2.txt`) instead of the shared one.
+/// The module is returned unchanged if the test could not be found.
+#[must_use]
+pub fn use_own_example(module: &str, part: u8) -> String {
+    match find_test(module, part) {
+        Some((start, end)) => format!(
+            "{}{}{}",
+            &module[..start],
+            read_own_example(&module[start..end], part),
+            &module[end..]
+        ),
+        None => module.to_string(),
+    }
+}
+
+/// Finds the start and end of the scaffolded test function of a part.
+fn find_test(module: &str, part: u8) -> Option<(usize, usize)> {
+    let name = if part == 1 {
+        "fn test_part_one()"
+    } else {
+        "fn test_part_two()"
+    };
+
+    let start = module.find(name)?;
+    let end = start + module[start..].find("\n    }")?;
+    Some((start, end))
+}
+
+fn read_own_example(body: &str, part: u8) -> String {
+    body.replace(
+        "read_file(\"examples\", YEAR, DAY)",
+        &format!("read_file_part(\"examples\", YEAR, DAY, {part})"),
+    )
+}
+
+/// Formats the expected result of a part test, e.g. `Some(4361)` or `None` if the answer is unknown.
+/// Answers of `String` and `&str` parts are written as strings, numbers of other parts as-is.
+/// Without a known answer type, numbers are written as-is and everything else as a `String`.
+#[must_use]
+pub fn expected(answer: Option<&str>, answer_type: Option<&str>) -> String {
+    let Some(answer) = answer else {
+        return "None".into();
+    };
+
+    match answer_type.map(|t| t.replace(' ', "")).as_deref() {
+        Some("String") => format!("Some({answer:?}.to_string())"),
+        Some("&str" | "&'staticstr") => format!("Some({answer:?})"),
+        _ if answer.parse::<i128>().is_ok() => format!("Some({answer})"),
+        _ => format!("Some({answer:?}.to_string())"),
+    }
+}
+
+/// Reads the answer type of a part from its signature in a solution module,
+/// e.g. `u64` for `pub fn part_one(input: &str) -> Option<u64>`.
+fn answer_type(module: &str, part: u8) -> Option<&str> {
+    let name = if part == 1 {
+        "fn part_one("
+    } else {
+        "fn part_two("
+    };
+
+    let start = module.find(name)?;
+    let signature = &module[start..start + module[start..].find('{')?];
+    let (_, output) = signature.split_once("->")?;
+    let (_, inner) = output.split_once("Option<")?;
+
+    let mut depth = 0;
+    for (i, c) in inner.char_indices() {
+        match c {
+            '<' => depth += 1,
+            '>' if depth == 0 => return Some(inner[..i].trim()),
+            '>' => depth -= 1,
+            _ => {}
+        }
+    }
+    None
+}
+
+/// Reads the title of a puzzle from its description, e.g. `Gear Ratios` for `--- Day 3: Gear Ratios ---`.
+#[must_use]
+pub fn title(markdown: &str) -> Option<String> {
+    let heading = markdown.lines().find(|l| l.contains("--- Day "))?;
+    let title = heading.split_once(": ")?.1;
+    let title = title.trim_end().trim_end_matches("---").trim_end();
+    (!title.is_empty()).then(|| title.to_string())
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{answer_type, emphasized_code, expected, extract, fill_in_test, title, Example};
+
+    #[test]
+    fn extracts_examples_of_read_markdown() {
+        let markdown = [
+            "## --- Day 1: Trebuchet?! ---",
+            "",
+            "The newly-improved calibration document consists of lines of text.",
+            "",
+            "```",
+            "not an example",
+            "```",
+            "",
+            "For example:",
+            "",
+            "```",
+            "1abc2",
+            "treb7uchet",
+            "```",
+            "",
+            "Adding these together produces *`142`*.",
+            "",
+            "*What is the sum of all of the calibration values?*",
+            "",
+            "## --- Part Two ---",
+            "",
+            "Equipped with this new information, you now need to find the real values:",
+            "",
+            "```",
+            "two1nine",
+            "```",
+            "",
+            "Adding these together produces *`29`*.",
+        ]
+        .join("\n");
+
+        let examples = extract(&markdown);
+        assert_eq!(
+            examples.part_one,
+            Example {
+                input: Some("1abc2\ntreb7uchet\n".into()),
+                answer: Some("142".into())
+            }
+        );
+        assert_eq!(
+            examples.part_two,
+            Example {
+                input: Some("two1nine\n".into()),
+                answer: Some("29".into())
+            }
+        );
+    }
+
+    #[test]
+    fn extracts_examples_of_aoc_cli_markdown() {
+        let markdown = [
+            "\\--- Day 3: Gear Ratios ---",
+            "----------",
+            "",
+            "Here is an example engine schematic:",
+            "",
+            "```",
+            "467..114..",
+            "...*......",
+            "",
+            "```",
+            "",
+            "Two numbers are *not* part numbers: `114` and `58`; their sum is `*4361*`.",
+            "",
+            "\\--- Part Two ---",
+            "----------",
+            "",
+            "Consider the same engine schematic again, the sum is `*467835*`.",
+        ]
+        .join("\n");
+
+        let examples = extract(&markdown);
+        assert_eq!(
+            examples.part_one,
+            Example {
+                input: Some("467..114..\n...*......\n".into()),
+                answer: Some("4361".into())
+            }
+        );
+        assert_eq!(
+            examples.part_two,
+            Example {
+                input: None,
+                answer: Some("467835".into())
+            }
+        );
+        assert_eq!(extract("no examples").part_two, Example::default());
+    }
+
+    #[test]
+    fn finds_emphasized_code() {
+        assert_eq!(
+            emphasized_code("a *`1`* b `*2*` *c* `d` *`x y`*"),
+            vec!["1", "2", "x y"]
+        );
+    }
+
+    #[test]
+    fn fills_in_tests() {
+        let module = r#"#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let result = part_one(&advent_of_code::template::read_file("examples", YEAR, DAY));
+        assert_eq!(result, None);
+    }
+
+    #[test]
+    fn test_part_two() {
+        let result = part_two(&advent_of_code::template::read_file("examples", YEAR, DAY));
+        assert_eq!(result, None);
+    }
+}
+"#;
+
+        let module = fill_in_test(module, 1, "142", false).unwrap();
+        assert!(module.contains("assert_eq!(result, Some(142));"));
+
+        let module = fill_in_test(&module, 2, "AB,C", true).unwrap();
+        assert!(module.contains(
+            r#"part_two(&advent_of_code::template::read_file_part("examples", YEAR, DAY, 2));"#
+        ));
+        assert!(module.contains(r#"assert_eq!(result, Some("AB,C".to_string()));"#));
+
+        assert_eq!(fill_in_test(&module, 1, "1", false), None);
+    }
+
+    #[test]
+    fn fills_in_tests_of_string_parts() {
+        let module = r#"pub fn part_one(input: &str) -> Option<String> {
+    None
+}
+
+pub fn part_two(input: &str) -> Option<Vec<u8>> {
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let result = part_one(&advent_of_code::template::read_file("examples", YEAR, DAY));
+        assert_eq!(result, None);
+    }
+}
+"#;
+
+        let module = fill_in_test(module, 1, "142", false).unwrap();
+        assert!(module.contains(r#"assert_eq!(result, Some("142".to_string()));"#));
+        assert_eq!(answer_type(&module, 2), Some("Vec<u8>"));
+    }
+
+    #[test]
+    fn reads_titles() {
+        assert_eq!(
+            title("## --- Day 3: Gear Ratios ---\n\nYou and the Elf").as_deref(),
+            Some("Gear Ratios")
+        );
+        assert_eq!(
+            title("\\--- Day 1: Trebuchet?! ---\n----------").as_deref(),
+            Some("Trebuchet?!")
+        );
+        assert_eq!(title("## --- Part Two ---"), None);
+    }
+
+    #[test]
+    fn formats_expected_results() {
+        assert_eq!(expected(Some("-12"), None), "Some(-12)");
+        assert_eq!(expected(Some("-12"), Some("i64")), "Some(-12)");
+        assert_eq!(expected(Some("a\"b"), None), r#"Some("a\"b".to_string())"#);
+        assert_eq!(
+            expected(Some("142"), Some("String")),
+            r#"Some("142".to_string())"#
+        );
+        assert_eq!(
+            expected(Some("142"), Some("&'static str")),
+            r#"Some("142")"#
+        );
+        assert_eq!(expected(None, Some("String")), "None");
+    }
+}
@@ -0,0 +1,244 @@
+/// Minimal reader and writer for flat JSON objects.
+/// Only the subset needed for exchanging records between the template commands is supported:
+/// a single object per line with string, number, boolean and null values.
+use std::collections::HashMap;
+use std::fmt::Write;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Value {
+    Null,
+    Bool(bool),
+    /// Numbers are kept in their textual form so that integers of any width survive a round trip.
+    Number(String),
+    String(String),
+}
+
+impl Value {
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            Value::String(s) => Some(s),
+            _ => None,
+        }
+    }
+
+    pub fn as_u128(&self) -> Option<u128> {
+        match self {
+            Value::Number(n) => n.parse().ok(),
+            _ => None,
+        }
+    }
+}
+
+impl From<&str> for Value {
+    fn from(s: &str) -> Self {
+        Value::String(s.into())
+    }
+}
+
+impl From<String> for Value {
+    fn from(s: String) -> Self {
+        Value::String(s)
+    }
+}
+
+impl<T: Into<Value>> From<Option<T>> for Value {
+    fn from(v: Option<T>) -> Self {
+        v.map_or(Value::Null, Into::into)
+    }
+}
+
+macro_rules! impl_number {
+    ($($t:ty),*) => {
+        $(impl From<$t> for Value {
+            fn from(n: $t) -> Self {
+                Value::Number(n.to_string())
+            }
+        })*
+    };
+}
+
+impl_number!(u8, u16, u32, u64, u128, usize, i32, i64, f64);
+
+impl From<bool> for Value {
+    fn from(b: bool) -> Self {
+        Value::Bool(b)
+    }
+}
+
+/// Serializes the given fields as a single-line JSON object, preserving field order.
+pub fn to_object(fields: &[(&str, Value)]) -> String {
+    let mut out = String::from("{");
+
+    for (i, (key, value)) in fields.iter().enumerate() {
+        if i > 0 {
+            out.push(',');
+        }
+        write_string(&mut out, key);
+        out.push(':');
+        match value {
+            Value::Null => out.push_str("null"),
+            Value::Bool(b) => out.push_str(if *b { "true" } else { "false" }),
+            Value::Number(n) => out.push_str(n),
+            Value::String(s) => write_string(&mut out, s),
+        }
+    }
+
+    out.push('}');
+    out
+}
+
+fn write_string(out: &mut String, s: &str) {
+    out.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if (c as u32) < 0x20 => {
+                let _ = write!(out, "\\u{:04x}", c as u32);
+            }
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+}
+
+/// Parses a single-line JSON object. Returns [`None`] if the input is not a flat object.
+pub fn parse_object(s: &str) -> Option<HashMap<String, Value>> {
+    let mut parser = Parser {
+        chars: s.trim().chars().peekable(),
+    };
+    let mut fields = HashMap::new();
+
+    parser.expect('{')?;
+    parser.skip_whitespace();
+
+    if parser.chars.peek() == Some(&'}') {
+        parser.chars.next();
+    } else {
+        loop {
+            parser.skip_whitespace();
+            let key = parser.string()?;
+            parser.skip_whitespace();
+            parser.expect(':')?;
+            parser.skip_whitespace();
+            let value = parser.value()?;
+            fields.insert(key, value);
+            parser.skip_whitespace();
+            match parser.chars.next()? {
+                ',' => continue,
+                '}' => break,
+                _ => return None,
+            }
+        }
+    }
+
+    if parser.chars.next().is_some() {
+        return None;
+    }
+
+    Some(fields)
+}
+
+struct Parser<'a> {
+    chars: std::iter::Peekable<std::str::Chars<'a>>,
+}
+
+impl Parser<'_> {
+    fn skip_whitespace(&mut self) {
+        while self.chars.peek().is_some_and(|c| c.is_whitespace()) {
+            self.chars.next();
+        }
+    }
+
+    fn expect(&mut self, c: char) -> Option<()> {
+        (self.chars.next()? == c).then_some(())
+    }
+
+    fn literal(&mut self, word: &str) -> Option<()> {
+        for expected in word.chars() {
+            self.expect(expected)?;
+        }
+        Some(())
+    }
+
+    fn value(&mut self) -> Option<Value> {
+        match self.chars.peek()? {
+            '"' => self.string().map(Value::String),
+            'n' => self.literal("null").map(|()| Value::Null),
+            't' => self.literal("true").map(|()| Value::Bool(true)),
+            'f' => self.literal("false").map(|()| Value::Bool(false)),
+            _ => self.number().map(Value::Number),
+        }
+    }
+
+    fn number(&mut self) -> Option<String> {
+        let mut n = String::new();
+        while let Some(&c) = self.chars.peek() {
+            if c.is_ascii_digit() || matches!(c, '-' | '+' | '.' | 'e' | 'E') {
+                n.push(c);
+                self.chars.next();
+            } else {
+                break;
+            }
+        }
+        (!n.is_empty()).then_some(n)
+    }
+
+    fn string(&mut self) -> Option<String> {
+        self.expect('"')?;
+        let mut s = String::new();
+        loop {
+            match self.chars.next()? {
+                '"' => return Some(s),
+                '\\' => match self.chars.next()? {
+                    'n' => s.push('\n'),
+                    'r' => s.push('\r'),
+                    't' => s.push('\t'),
+                    'b' => s.push('\u{8}'),
+                    'f' => s.push('\u{c}'),
+                    'u' => {
+                        let code: String = (0..4).filter_map(|_| self.chars.next()).collect();
+                        s.push(char::from_u32(u32::from_str_radix(&code, 16).ok()?)?);
+                    }
+                    c => s.push(c),
+                },
+                c => s.push(c),
+            }
+        }
+    }
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{parse_object, to_object, Value};
+
+    #[test]
+    fn round_trip() {
+        let line = to_object(&[
+            ("day", 1_u8.into()),
+            ("answer", "a \"quoted\"\nline".into()),
+            ("missing", Option::<String>::None.into()),
+            ("flag", true.into()),
+        ]);
+        assert_eq!(
+            line,
+            r#"{"day":1,"answer":"a \"quoted\"\nline","missing":null,"flag":true}"#
+        );
+
+        let parsed = parse_object(&line).unwrap();
+        assert_eq!(parsed["day"].as_u128(), Some(1));
+        assert_eq!(parsed["answer"].as_str(), Some("a \"quoted\"\nline"));
+        assert_eq!(parsed["missing"], Value::Null);
+        assert_eq!(parsed["flag"], Value::Bool(true));
+    }
+
+    #[test]
+    fn rejects_non_objects() {
+        assert!(parse_object("Part 1: 42 (1.0ms)").is_none());
+        assert!(parse_object("{\"a\":1} trailing").is_none());
+        assert!(parse_object("{\"a\":}").is_none());
+    }
+}
@@ -0,0 +1,290 @@
+//! Converts the HTML of an Advent of Code puzzle page to markdown.
+//! Only the tags used in puzzle descriptions are handled, everything else is reduced to its text.
+
+/// Converts the puzzle descriptions (`<article class="day-desc">`) of a puzzle page to markdown.
+/// Both parts are included once part one has been solved.
+#[must_use]
+pub fn from_puzzle_html(html: &str) -> String {
+    let articles: Vec<&str> = html
+        .split("<article")
+        .skip(1)
+        .filter(|a| a.starts_with(" class=\"day-desc\""))
+        .filter_map(|a| a.split_once('>').map(|x| x.1))
+        .filter_map(|a| a.split_once("</article>").map(|x| x.0))
+        .collect();
+
+    articles
+        .iter()
+        .map(|a| convert(a))
+        .collect::<Vec<_>>()
+        .join("\n\n")
+        + "\n"
+}
+
+/// Extracts the text of the first `<article>` element, e.g. the message returned after submitting an answer.
+#[must_use]
+pub fn article_text(html: &str) -> Option<String> {
+    let start = html.find("<article")?;
+    let body = html[start..].split_once('>')?.1;
+    let body = body.split_once("</article>")?.0;
+    Some(
+        strip_tags(body)
+            .split_whitespace()
+            .collect::<Vec<_>>()
+            .join(" "),
+    )
+}
+
+/// Removes all tags and decodes entities.
+fn strip_tags(html: &str) -> String {
+    tokenize(html)
+        .into_iter()
+        .filter_map(|t| match t {
+            Token::Text(s) => Some(s),
+            Token::Open(..) | Token::Close(_) => None,
+        })
+        .collect()
+}
+
+#[derive(Debug, PartialEq)]
+enum Token {
+    /// Opening tag with its name and `href` attribute, if any.
+    Open(String, Option<String>),
+    Close(String),
+    Text(String),
+}
+
+fn tokenize(html: &str) -> Vec<Token> {
+    let mut tokens = vec![];
+    let mut rest = html;
+
+    while !rest.is_empty() {
+        if let Some(tag) = rest.strip_prefix('<') {
+            let Some((tag, after)) = tag.split_once('>') else {
+                tokens.push(Token::Text(decode_entities(rest)));
+                break;
+            };
+            rest = after;
+
+            if let Some(name) = tag.strip_prefix('/') {
+                tokens.push(Token::Close(name.trim().to_lowercase()));
+            } else {
+                let tag = tag.trim_end_matches('/');
+                let name = tag.split_whitespace().next().unwrap_or("").to_lowercase();
+                let href = tag
+                    .split_once("href=\"")
+                    .and_then(|(_, h)| h.split_once('"'))
+                    .map(|(h, _)| decode_entities(h));
+                tokens.push(Token::Open(name, href));
+            }
+        } else {
+            let end = rest.find('<').unwrap_or(rest.len());
+            tokens.push(Token::Text(decode_entities(&rest[..end])));
+            rest = &rest[end..];
+        }
+    }
+
+    tokens
+}
+
+fn decode_entities(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    let mut rest = s;
+
+    while let Some(pos) = rest.find('&') {
+        out.push_str(&rest[..pos]);
+        rest = &rest[pos..];
+
+        let decoded = rest.find(';').and_then(|end| {
+            let entity = &rest[1..end];
+            let c = match entity {
+                "lt" => Some('<'),
+                "gt" => Some('>'),
+                "amp" => Some('&'),
+                "quot" => Some('"'),
+                "apos" => Some('\''),
+                "nbsp" => Some(' '),
+                e if e.starts_with("#x") => u32::from_str_radix(&e[2..], 16)
+                    .ok()
+                    .and_then(char::from_u32),
+                e if e.starts_with('#') => e[1..].parse().ok().and_then(char::from_u32),
+                _ => None,
+            }?;
+            Some((c, end))
+        });
+
+        match decoded {
+            Some((c, end)) => {
+                out.push(c);
+                rest = &rest[end + 1..];
+            }
+            None => {
+                out.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+
+    out.push_str(rest);
+    out
+}
+
+fn convert(html: &str) -> String {
+    let mut blocks: Vec<String> = vec![];
+    let mut current = String::new();
+    let mut pre_depth = 0;
+    // text of the inline code span being collected and whether it contained emphasis.
+    let mut code: Option<(String, bool)> = None;
+    let mut links: Vec<Option<String>> = vec![];
+
+    for token in tokenize(html) {
+        match token {
+            Token::Text(text) => {
+                if let Some((code, _)) = code.as_mut() {
+                    code.push_str(&text);
+                } else if pre_depth > 0 {
+                    current.push_str(&text);
+                } else {
+                    // collapse whitespace like a browser would.
+                    let mut collapsed = text.split_whitespace().collect::<Vec<_>>().join(" ");
+                    if text.starts_with(char::is_whitespace) && !current.ends_with([' ', '\n']) {
+                        collapsed.insert(0, ' ');
+                    }
+                    if text.ends_with(char::is_whitespace) && !collapsed.is_empty() {
+                        collapsed.push(' ');
+                    }
+                    current.push_str(&collapsed);
+                }
+            }
+            Token::Open(name, href) => match name.as_str() {
+                "h2" => {
+                    flush(&mut current, &mut blocks);
+                    current.push_str("## ");
+                }
+                "p" | "ul" => flush(&mut current, &mut blocks),
+                "li" => {
+                    if !current.is_empty() {
+                        current.push('\n');
+                    }
+                    current.push_str("- ");
+                }
+                "pre" => {
+                    flush(&mut current, &mut blocks);
+                    current.push_str("```\n");
+                    pre_depth += 1;
+                }
+                "code" if pre_depth == 0 => code = Some((String::new(), false)),
+                "em" => match code.as_mut() {
+                    Some((_, emphasized)) => *emphasized = true,
+                    None if pre_depth == 0 => current.push('*'),
+                    None => {}
+                },
+                "a" => {
+                    current.push('[');
+                    links.push(href);
+                }
+                _ => {}
+            },
+            Token::Close(name) => match name.as_str() {
+                "h2" | "p" | "ul" => flush(&mut current, &mut blocks),
+                "pre" => {
+                    pre_depth -= 1;
+                    if !current.ends_with('\n') {
+                        current.push('\n');
+                    }
+                    current.push_str("```");
+                    flush(&mut current, &mut blocks);
+                }
+                "code" if pre_depth == 0 => {
+                    if let Some((text, emphasized)) = code.take() {
+                        if emphasized {
+                            current.push_str(&format!("*`{text}`*"));
+                        } else {
+                            current.push_str(&format!("`{text}`"));
+                        }
+                    }
+                }
+                "em" if code.is_none() && pre_depth == 0 => current.push('*'),
+                "a" => {
+                    current.push(']');
+                    if let Some(Some(href)) = links.pop() {
+                        current.push_str(&format!("({href})"));
+                    }
+                }
+                _ => {}
+            },
+        }
+    }
+
+    flush(&mut current, &mut blocks);
+    blocks.join("\n\n")
+}
+
+/// Moves the collected text into a new block.
+fn flush(current: &mut String, blocks: &mut Vec<String>) {
+    let block = current.trim_end().to_string();
+    if !block.trim().is_empty() {
+        blocks.push(block);
+    }
+    current.clear();
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{article_text, from_puzzle_html};
+
+    #[test]
+    fn converts_puzzle_description() {
+        let html = r#"<main>
+<article class="day-desc"><h2>--- Day 1: Trebuchet?! ---</h2><p>Something is <em>wrong</em> with <a href="/2023/events">global</a> snow &amp; ice.</p>
+<p>For example:</p>
+<pre><code>1abc2
+pqr3stu8vwx
+</code></pre>
+<ul><li>One <code>1</code></li><li>Two</li></ul>
+<p>Adding these together produces <code><em>142</em></code>.</p>
+</article>
+<p>Answer: <input type="text" name="answer"/></p>
+</main>"#;
+
+        let expected = [
+            "## --- Day 1: Trebuchet?! ---",
+            "",
+            "Something is *wrong* with [global](/2023/events) snow & ice.",
+            "",
+            "For example:",
+            "",
+            "```",
+            "1abc2",
+            "pqr3stu8vwx",
+            "```",
+            "",
+            "- One `1`",
+            "- Two",
+            "",
+            "Adding these together produces *`142`*.",
+            "",
+        ]
+        .join("\n");
+
+        assert_eq!(from_puzzle_html(html), expected);
+    }
+
+    #[test]
+    fn joins_both_parts() {
+        let html = r#"<article class="day-desc"><h2>--- Day 2 ---</h2></article><p>Your puzzle answer was <code>8</code>.</p><article class="day-desc"><h2 id="part2">--- Part Two ---</h2></article>"#;
+        assert_eq!(
+            from_puzzle_html(html),
+            "## --- Day 2 ---\n\n## --- Part Two ---\n"
+        );
+    }
+
+    #[test]
+    fn extracts_article_text() {
+        let html = "<main>\n<article><p>That's the <em>right answer</em>!  You are <span class=\"x\">one gold star</span> closer.</p></article></main>";
+        assert_eq!(
+            article_text(html).unwrap(),
+            "That's the right answer! You are one gold star closer."
+        );
+    }
+}
@@ -1,48 +1,116 @@
-use crate::Day;
-use std::{env, fs};
+use crate::{Day, Year};
+use std::{env, fs, io};
 
-pub mod aoc_cli;
+pub mod answers;
+pub mod aoc_client;
+pub mod benchmark_history;
 pub mod commands;
+pub mod examples;
+mod json;
+pub mod markdown;
+pub mod profiles;
 pub mod readme_benchmarks;
+pub mod record;
+pub mod registry;
 pub mod runner;
+pub mod stats;
 
 pub const ANSI_ITALIC: &str = "\x1b[3m";
 pub const ANSI_BOLD: &str = "\x1b[1m";
 pub const ANSI_RESET: &str = "\x1b[0m";
 
-/// Helper function that reads a text file to a string.
+/// Helper function that reads a text file of a year to a string.
 #[must_use]
-pub fn read_file(folder: &str, day: Day) -> String {
+pub fn read_file(folder: &str, year: Year, day: Day) -> String {
     let cwd = env::current_dir().unwrap();
-    let filepath = cwd.join("data").join(folder).join(format!("{day}.txt"));
+    let filepath = cwd
+        .join("data")
+        .join(year.to_string())
+        .join(folder)
+        .join(format!("{day}.txt"));
     let f = fs::read_to_string(filepath);
     f.expect("could not open input file")
 }
 
-/// Helper function that reads a text file to string, appending a part suffix. E.g. like `01-2.txt`.
+/// Reads the puzzle input of a day, returning an error instead of panicking if it is missing.
+pub fn read_input(year: Year, day: Day) -> io::Result<String> {
+    fs::read_to_string(
+        env::current_dir()?
+            .join("data")
+            .join(year.to_string())
+            .join("inputs")
+            .join(format!("{day}.txt")),
+    )
+}
+
+/// Helper function that reads a text file of a year to string, appending a part suffix. E.g. like `01-2.txt`.
 #[must_use]
-pub fn read_file_part(folder: &str, day: Day, part: u8) -> String {
+pub fn read_file_part(folder: &str, year: Year, day: Day, part: u8) -> String {
     let cwd = env::current_dir().unwrap();
     let filepath = cwd
         .join("data")
+        .join(year.to_string())
         .join(folder)
         .join(format!("{day}-{part}.txt"));
     let f = fs::read_to_string(filepath);
     f.expect("could not open input file")
 }
 
-/// Creates the constant `DAY` and sets up the input and runner for each part.
+/// Returns the path of the solution of a day, e.g. `./src/bin/2023-01.rs`.
+#[must_use]
+pub fn get_path_for_bin(year: Year, day: Day) -> String {
+    format!("./src/bin/{year}-{day}.rs")
+}
+
+/// Creates the constants `YEAR` and `DAY` and sets up the input and runner for each part.
+///
+/// Pass the name of a parse function as third argument to parse the input once, ahead of both parts.
+/// The parts then take a reference to the parsed value, e.g. `solution!(2023, 5, parse)` with
+/// `fn parse(input: &str) -> Almanac` and `fn part_one(almanac: &Almanac) -> Option<u64>`.
+/// The runner times the parse step separately from the parts.
+///
+/// The generated `main` reads the puzzle input of the day, `--input <path>` (`-` for stdin) and
+/// `--example` run the solution against another input instead.
 #[macro_export]
 macro_rules! solution {
-    ($day:expr) => {
+    (@consts $year:expr, $day:expr, $run:expr) => {
+        /// The year of this solution.
+        const YEAR: advent_of_code::Year = advent_of_code::year!($year);
         /// The current day.
         const DAY: advent_of_code::Day = advent_of_code::day!($day);
 
+        /// Entry point of this solution, used to run it in-process.
+        #[allow(dead_code)]
+        pub const SOLUTION: advent_of_code::template::registry::Solution =
+            advent_of_code::template::registry::Solution {
+                year: YEAR,
+                day: DAY,
+                run: $run,
+            };
+    };
+    ($year:expr, $day:expr) => {
+        $crate::solution!(@consts $year, $day, |input, budget| {
+            advent_of_code::template::runner::run_records(part_one, part_two, input, YEAR, DAY, budget)
+        });
+
+        fn main() {
+            use advent_of_code::template::runner::*;
+            let input = read_input(YEAR, DAY);
+            run_part(part_one, &input, YEAR, DAY, 1);
+            run_part(part_two, &input, YEAR, DAY, 2);
+        }
+    };
+    ($year:expr, $day:expr, $parse:ident) => {
+        $crate::solution!(@consts $year, $day, |input, budget| {
+            advent_of_code::template::runner::run_parsed_records(
+                $parse, part_one, part_two, input, YEAR, DAY, budget,
+            )
+        });
+
         fn main() {
             use advent_of_code::template::runner::*;
-            let input = advent_of_code::template::read_file("inputs", DAY);
-            run_part(part_one, &input, DAY, 1);
-            run_part(part_two, &input, DAY, 2);
+            let input = read_input(YEAR, DAY);
+            run_parsed($parse, part_one, part_two, &input, YEAR, DAY);
         }
     };
 }
@@ -0,0 +1,161 @@
+/// Input profiles hold the puzzle inputs of other accounts, e.g. `data/2023/inputs/alice/05.txt`,
+/// together with their expected answers in `data/2023/inputs/alice/expected.txt`.
+/// The inputs directly in `data/2023/inputs` form the `default` profile.
+use std::{fs, io, str::FromStr};
+
+use crate::template::answers::{self, ExpectedAnswers};
+use crate::{Day, Year};
+
+/// Name of the profile of the puzzle inputs in `data/<year>/inputs`.
+pub const DEFAULT: &str = "default";
+
+/// Profiles selected with `--profiles`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Selection {
+    /// The default profile and all profile directories.
+    All,
+    Names(Vec<String>),
+}
+
+impl FromStr for Selection {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.trim() == "all" {
+            return Ok(Self::All);
+        }
+
+        let names: Vec<String> = s
+            .split(',')
+            .map(str::trim)
+            .filter(|name| !name.is_empty())
+            .map(Into::into)
+            .collect();
+
+        if names.is_empty() {
+            Err("expected `all` or a comma-separated list of profiles".into())
+        } else {
+            Ok(Self::Names(names))
+        }
+    }
+}
+
+#[must_use]
+pub fn get_path(year: Year, profile: &str) -> String {
+    if profile == DEFAULT {
+        format!("data/{year}/inputs")
+    } else {
+        format!("data/{year}/inputs/{profile}")
+    }
+}
+
+#[must_use]
+pub fn get_input_path(year: Year, profile: &str, day: Day) -> String {
+    format!("{}/{day}.txt", get_path(year, profile))
+}
+
+/// Returns the names of the profile directories of a year, in alphabetical order.
+pub fn list(year: Year) -> io::Result<Vec<String>> {
+    let entries = match fs::read_dir(get_path(year, DEFAULT)) {
+        Ok(entries) => entries,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(vec![]),
+        Err(e) => return Err(e),
+    };
+
+    let mut profiles = vec![];
+
+    for entry in entries {
+        let entry = entry?;
+        if entry.file_type()?.is_dir() {
+            profiles.push(entry.file_name().to_string_lossy().into_owned());
+        }
+    }
+
+    profiles.sort_unstable();
+    Ok(profiles)
+}
+
+/// Returns the profiles of a selection, starting with the default profile for `all`.
+/// Fails if a selected profile does not exist.
+pub fn resolve(selection: &Selection, available: &[String]) -> Result<Vec<String>, String> {
+    match selection {
+        Selection::All => Ok(std::iter::once(DEFAULT.to_string())
+            .chain(available.iter().cloned())
+            .collect()),
+        Selection::Names(names) => {
+            for name in names {
+                if name != DEFAULT && !available.contains(name) {
+                    return Err(format!(
+                        "unknown profile \"{name}\", available profiles: {}",
+                        std::iter::once(DEFAULT)
+                            .chain(available.iter().map(String::as_str))
+                            .collect::<Vec<_>>()
+                            .join(", ")
+                    ));
+                }
+            }
+            Ok(names.clone())
+        }
+    }
+}
+
+/// Reads the input of a day from a profile.
+pub fn read_input(year: Year, profile: &str, day: Day) -> io::Result<String> {
+    fs::read_to_string(get_input_path(year, profile, day))
+}
+
+/// Reads the expected answers of a profile.
+/// The default profile uses the expected answers file of the year, see [`answers::load_expected`].
+pub fn load_expected(year: Year, profile: &str) -> Result<ExpectedAnswers, answers::Error> {
+    if profile == DEFAULT {
+        answers::load_expected(year)
+    } else {
+        answers::load_expected_from(&format!("{}/expected.txt", get_path(year, profile)))
+    }
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{get_input_path, resolve, Selection};
+    use crate::{day, year};
+
+    #[test]
+    fn parses_selections() {
+        assert_eq!("all".parse(), Ok(Selection::All));
+        assert_eq!(
+            "alice, bob".parse(),
+            Ok(Selection::Names(vec!["alice".into(), "bob".into()]))
+        );
+        assert!(" , ".parse::<Selection>().is_err());
+    }
+
+    #[test]
+    fn resolves_selections() {
+        let available = ["alice".to_string(), "bob".to_string()];
+
+        assert_eq!(
+            resolve(&Selection::All, &available).unwrap(),
+            ["default", "alice", "bob"]
+        );
+        assert_eq!(
+            resolve(&"default,bob".parse().unwrap(), &available).unwrap(),
+            ["default", "bob"]
+        );
+        assert_eq!(
+            resolve(&"carol".parse().unwrap(), &available),
+            Err("unknown profile \"carol\", available profiles: default, alice, bob".into())
+        );
+    }
+
+    #[test]
+    fn builds_input_paths() {
+        assert_eq!(
+            get_input_path(year!(2023), "default", day!(5)),
+            "data/2023/inputs/05.txt"
+        );
+        assert_eq!(
+            get_input_path(year!(2023), "alice", day!(5)),
+            "data/2023/inputs/alice/05.txt"
+        );
+    }
+}
@@ -1,8 +1,9 @@
 /// Module that updates the readme me with timing information.
 /// The approach taken is similar to how `aoc-readme-stars` handles this.
-use std::{fs, io};
+use std::{collections::BTreeMap, fs, io};
 
-use crate::Day;
+use crate::template::{get_path_for_bin, stats::BenchStats};
+use crate::{Day, Year};
 
 static MARKER: &str = "<!--- benchmarking table --->";
 
@@ -21,8 +22,12 @@ impl From<std::io::Error> for Error {
 #[derive(Clone)]
 pub struct Timings {
     pub day: Day,
+    /// Time of the parse step shared by both parts, if the solution has one.
+    pub parse: Option<String>,
     pub part_1: Option<String>,
     pub part_2: Option<String>,
+    pub part_1_stats: Option<BenchStats>,
+    pub part_2_stats: Option<BenchStats>,
     pub total_nanos: f64,
 }
 
@@ -31,11 +36,6 @@ pub struct TablePosition {
     pos_end: usize,
 }
 
-#[must_use]
-pub fn get_path_for_bin(day: Day) -> String {
-    format!("./src/bin/{day}.rs")
-}
-
 fn locate_table(readme: &str) -> Result<TablePosition, Error> {
     let matches: Vec<_> = readme.match_indices(MARKER).collect();
 
@@ -58,74 +58,221 @@ fn locate_table(readme: &str) -> Result<TablePosition, Error> {
     Ok(TablePosition { pos_start, pos_end })
 }
 
-fn construct_table(prefix: &str, timings: Vec<Timings>, total_millis: f64) -> String {
+/// Benchmark tables, keyed by year.
+type Tables = BTreeMap<Year, Vec<Timings>>;
+
+fn construct_table(prefix: &str, tables: Tables) -> String {
     let header = format!("{prefix} Benchmarks");
 
-    let mut lines: Vec<String> = vec![
-        MARKER.into(),
-        header,
-        String::new(),
-        "| Day | Part 1 | Part 2 |".into(),
-        "| :---: | :---: | :---:  |".into(),
-    ];
-
-    for timing in timings {
-        let path = get_path_for_bin(timing.day);
-        lines.push(format!(
-            "| [Day {}]({}) | `{}` | `{}` |",
-            timing.day.into_inner(),
-            path,
-            timing.part_1.unwrap_or_else(|| "-".into()),
-            timing.part_2.unwrap_or_else(|| "-".into())
-        ));
+    let mut lines: Vec<String> = vec![MARKER.into(), header];
+
+    // the most recent year goes first.
+    for (year, timings) in tables.into_iter().rev() {
+        let total_millis = timings.iter().map(|x| x.total_nanos).sum::<f64>() / 1_000_000_f64;
+
+        lines.push(String::new());
+        lines.push(format!("{prefix}# {year}"));
+        lines.push(String::new());
+        lines.push("| Day | Parse | Part 1 | Part 2 |".into());
+        lines.push("| :---: | :---: | :---: | :---:  |".into());
+
+        for timing in timings {
+            let path = get_path_for_bin(year, timing.day);
+            lines.push(format!(
+                "| [Day {}]({}) | `{}` | `{}` | `{}` |",
+                timing.day.into_inner(),
+                path,
+                timing.parse.unwrap_or_else(|| "-".into()),
+                timing.part_1.unwrap_or_else(|| "-".into()),
+                timing.part_2.unwrap_or_else(|| "-".into())
+            ));
+        }
+
+        lines.push(String::new());
+        lines.push(format!("**Total: {total_millis:.2}ms**"));
     }
 
-    lines.push(String::new());
-    lines.push(format!("**Total: {total_millis:.2}ms**"));
     lines.push(MARKER.into());
 
     lines.join("\n")
 }
 
-fn update_content(s: &mut String, timings: Vec<Timings>, total_millis: f64) -> Result<(), Error> {
+/// Parses a duration as formatted by `{:.1?}`, e.g. `21.5µs`, into nanoseconds.
+fn parse_nanos(s: &str) -> Option<f64> {
+    let unit_pos = s.find(|c: char| c.is_alphabetic())?;
+    let (value, unit) = s.split_at(unit_pos);
+    let factor = match unit {
+        "ns" => 1.0,
+        "µs" => 1e3,
+        "ms" => 1e6,
+        "s" => 1e9,
+        _ => return None,
+    };
+    Some(value.parse::<f64>().ok()? * factor)
+}
+
+/// Reads the rows of the existing benchmark tables. The year of a row is taken from the path of its solution.
+/// Rows that cannot be parsed are dropped.
+fn parse_rows(table: &str) -> Tables {
+    let mut tables = Tables::new();
+
+    let rows = table.lines().filter_map(|line| {
+        let mut cells = line.trim().strip_prefix("| [Day ")?.split('|');
+        let (day, path) = cells.next()?.split_once("](")?;
+        let day = day.parse::<Day>().ok()?;
+        let year = path
+            .rsplit_once('/')?
+            .1
+            .split_once('-')?
+            .0
+            .parse::<Year>()
+            .ok()?;
+        let times: Vec<Option<String>> = cells
+            .map(|cell| cell.trim().trim_matches('`'))
+            .filter(|time| !time.is_empty())
+            .map(|time| (time != "-").then(|| time.to_string()))
+            .collect();
+        // tables written before the parse column was added only have the two parts.
+        let (parse, part_1, part_2) = match times.as_slice() {
+            [part_1, part_2] => (None, part_1.clone(), part_2.clone()),
+            [parse, part_1, part_2] => (parse.clone(), part_1.clone(), part_2.clone()),
+            _ => return None,
+        };
+
+        let timings = Timings {
+            day,
+            total_nanos: [&parse, &part_1, &part_2]
+                .into_iter()
+                .flatten()
+                .filter_map(|t| parse_nanos(t))
+                .sum(),
+            parse,
+            part_1,
+            part_2,
+            part_1_stats: None,
+            part_2_stats: None,
+        };
+        Some((year, timings))
+    });
+
+    for (year, timings) in rows {
+        tables.entry(year).or_default().push(timings);
+    }
+
+    tables
+}
+
+/// Merges new timings of a year into the existing tables.
+/// Rows of days in `days` are replaced by their new timings (or removed if there are none), other rows are kept.
+fn merge(mut tables: Tables, year: Year, days: &[Day], timings: Vec<Timings>) -> Tables {
+    let existing = tables.remove(&year).unwrap_or_default();
+    let mut merged: Vec<Timings> = existing
+        .into_iter()
+        .filter(|t| !days.contains(&t.day))
+        .chain(timings)
+        .collect();
+    merged.sort_by_key(|t| t.day);
+
+    if !merged.is_empty() {
+        tables.insert(year, merged);
+    }
+    tables
+}
+
+fn update_content(
+    s: &mut String,
+    year: Year,
+    days: &[Day],
+    timings: Vec<Timings>,
+) -> Result<(), Error> {
     let positions = locate_table(s)?;
-    let table = construct_table("##", timings, total_millis);
+    let existing = parse_rows(&s[positions.pos_start..positions.pos_end]);
+    let table = construct_table("##", merge(existing, year, days, timings));
     s.replace_range(positions.pos_start..positions.pos_end, &table);
     Ok(())
 }
 
-pub fn update(timings: Vec<Timings>, total_millis: f64) -> Result<(), Error> {
+/// Adds an empty row for a day to the benchmark table of its year, unless the day has a row already.
+/// Returns whether a row was added.
+fn add_row_to_content(s: &mut String, year: Year, day: Day) -> Result<bool, Error> {
+    let positions = locate_table(s)?;
+    let mut tables = parse_rows(&s[positions.pos_start..positions.pos_end]);
+    let timings = tables.entry(year).or_default();
+
+    if timings.iter().any(|t| t.day == day) {
+        return Ok(false);
+    }
+
+    timings.push(Timings {
+        day,
+        parse: None,
+        part_1: None,
+        part_2: None,
+        part_1_stats: None,
+        part_2_stats: None,
+        total_nanos: 0_f64,
+    });
+    timings.sort_by_key(|t| t.day);
+
+    let table = construct_table("##", tables);
+    s.replace_range(positions.pos_start..positions.pos_end, &table);
+    Ok(true)
+}
+
+/// Adds a placeholder row for a new day to the benchmark table in the readme.
+/// Returns whether a row was added.
+pub fn add_row(year: Year, day: Day) -> Result<bool, Error> {
+    let path = "README.md";
+    let mut readme = String::from_utf8_lossy(&fs::read(path)?).to_string();
+    let added = add_row_to_content(&mut readme, year, day)?;
+    if added {
+        fs::write(path, &readme)?;
+    }
+    Ok(added)
+}
+
+/// Updates the benchmark table of a year in the readme with the timings of a run that covered `days`.
+pub fn update(year: Year, days: &[Day], timings: Vec<Timings>) -> Result<(), Error> {
     let path = "README.md";
     let mut readme = String::from_utf8_lossy(&fs::read(path)?).to_string();
-    update_content(&mut readme, timings, total_millis)?;
+    update_content(&mut readme, year, days, timings)?;
     fs::write(path, &readme)?;
     Ok(())
 }
 
 #[cfg(feature = "test_lib")]
 mod tests {
-    use super::{update_content, Timings, MARKER};
-    use crate::day;
+    use super::{add_row_to_content, parse_nanos, update_content, Timings, MARKER};
+    use crate::{all_days, day, year};
 
     fn get_mock_timings() -> Vec<Timings> {
         vec![
             Timings {
                 day: day!(1),
+                parse: None,
                 part_1: Some("10ms".into()),
                 part_2: Some("20ms".into()),
-                total_nanos: 3e+10,
+                part_1_stats: None,
+                part_2_stats: None,
+                total_nanos: 3e+7,
             },
             Timings {
                 day: day!(2),
+                parse: None,
                 part_1: Some("30ms".into()),
                 part_2: Some("40ms".into()),
-                total_nanos: 7e+10,
+                part_1_stats: None,
+                part_2_stats: None,
+                total_nanos: 7e+7,
             },
             Timings {
                 day: day!(4),
+                parse: None,
                 part_1: Some("40ms".into()),
                 part_2: Some("50ms".into()),
-                total_nanos: 9e+10,
+                part_1_stats: None,
+                part_2_stats: None,
+                total_nanos: 9e+7,
             },
         ]
     }
@@ -134,28 +281,58 @@ mod tests {
     #[should_panic]
     fn errors_if_marker_not_present() {
         let mut s = "# readme".to_string();
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(
+            &mut s,
+            year!(2023),
+            &all_days().collect::<Vec<_>>(),
+            get_mock_timings(),
+        )
+        .unwrap();
     }
 
     #[test]
     #[should_panic]
     fn errors_if_too_many_markers_present() {
         let mut s = format!("{} {} {}", MARKER, MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(
+            &mut s,
+            year!(2023),
+            &all_days().collect::<Vec<_>>(),
+            get_mock_timings(),
+        )
+        .unwrap();
     }
 
     #[test]
     fn updates_empty_benchmarks() {
         let mut s = format!("foo\nbar\n{}{}\nbaz", MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(
+            &mut s,
+            year!(2023),
+            &all_days().collect::<Vec<_>>(),
+            get_mock_timings(),
+        )
+        .unwrap();
         assert_eq!(s.contains("## Benchmarks"), true);
     }
 
     #[test]
     fn updates_existing_benchmarks() {
         let mut s = format!("foo\nbar\n{}{}\nbaz", MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(
+            &mut s,
+            year!(2023),
+            &all_days().collect::<Vec<_>>(),
+            get_mock_timings(),
+        )
+        .unwrap();
+        update_content(
+            &mut s,
+            year!(2023),
+            &all_days().collect::<Vec<_>>(),
+            get_mock_timings(),
+        )
+        .unwrap();
         assert_eq!(s.matches(MARKER).collect::<Vec<&str>>().len(), 2);
         assert_eq!(s.matches("## Benchmarks").collect::<Vec<&str>>().len(), 1);
     }
@@ -163,18 +340,26 @@ mod tests {
     #[test]
     fn format_benchmarks() {
         let mut s = format!("foo\nbar\n{}\n{}\nbaz", MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(
+            &mut s,
+            year!(2023),
+            &all_days().collect::<Vec<_>>(),
+            get_mock_timings(),
+        )
+        .unwrap();
         let expected = [
             "foo",
             "bar",
             "<!--- benchmarking table --->",
             "## Benchmarks",
             "",
-            "| Day | Part 1 | Part 2 |",
-            "| :---: | :---: | :---:  |",
-            "| [Day 1](./src/bin/01.rs) | `10ms` | `20ms` |",
-            "| [Day 2](./src/bin/02.rs) | `30ms` | `40ms` |",
-            "| [Day 4](./src/bin/04.rs) | `40ms` | `50ms` |",
+            "### 2023",
+            "",
+            "| Day | Parse | Part 1 | Part 2 |",
+            "| :---: | :---: | :---: | :---:  |",
+            "| [Day 1](./src/bin/2023-01.rs) | `-` | `10ms` | `20ms` |",
+            "| [Day 2](./src/bin/2023-02.rs) | `-` | `30ms` | `40ms` |",
+            "| [Day 4](./src/bin/2023-04.rs) | `-` | `40ms` | `50ms` |",
             "",
             "**Total: 190.00ms**",
             "<!--- benchmarking table --->",
@@ -183,4 +368,120 @@ mod tests {
         .join("\n");
         assert_eq!(s, expected);
     }
+
+    #[test]
+    fn parses_durations() {
+        assert_eq!(parse_nanos("19.0ns"), Some(19.0));
+        assert_eq!(parse_nanos("21.5µs"), Some(21_500.0));
+        assert_eq!(parse_nanos("3.0ms"), Some(3_000_000.0));
+        assert_eq!(parse_nanos("1.5s"), Some(1_500_000_000.0));
+        assert_eq!(parse_nanos("-"), None);
+    }
+
+    #[test]
+    fn merges_touched_rows() {
+        let mut s = format!("foo\n{}\n{}\nbaz", MARKER, MARKER);
+        update_content(
+            &mut s,
+            year!(2023),
+            &all_days().collect::<Vec<_>>(),
+            get_mock_timings(),
+        )
+        .unwrap();
+
+        let timings = vec![Timings {
+            day: day!(2),
+            parse: None,
+            part_1: Some("5.0ms".into()),
+            part_2: None,
+            part_1_stats: None,
+            part_2_stats: None,
+            total_nanos: 5e+6,
+        }];
+        update_content(&mut s, year!(2023), &[day!(2), day!(4)], timings).unwrap();
+
+        assert!(s.contains("| [Day 1](./src/bin/2023-01.rs) | `-` | `10ms` | `20ms` |"));
+        assert!(s.contains("| [Day 2](./src/bin/2023-02.rs) | `-` | `5.0ms` | `-` |"));
+        assert!(!s.contains("[Day 4]"));
+        assert!(s.contains("**Total: 35.00ms**"));
+    }
+
+    #[test]
+    fn keeps_tables_of_other_years() {
+        let mut s = format!("foo\n{}\n{}\nbaz", MARKER, MARKER);
+        let days = all_days().collect::<Vec<_>>();
+        update_content(&mut s, year!(2022), &days, get_mock_timings()).unwrap();
+        update_content(&mut s, year!(2023), &days, get_mock_timings()[..1].to_vec()).unwrap();
+
+        let (newer, older) = s.split_once("### 2022").unwrap();
+        assert!(newer.contains("### 2023"));
+        assert!(newer.contains("| [Day 1](./src/bin/2023-01.rs) | `-` | `10ms` | `20ms` |"));
+        assert!(newer.contains("**Total: 30.00ms**"));
+        assert!(older.contains("| [Day 4](./src/bin/2022-04.rs) | `-` | `40ms` | `50ms` |"));
+        assert!(older.contains("**Total: 190.00ms**"));
+    }
+
+    #[test]
+    fn reads_tables_without_parse_column() {
+        let mut s = [
+            "foo",
+            MARKER,
+            "## Benchmarks",
+            "",
+            "### 2023",
+            "",
+            "| Day | Part 1 | Part 2 |",
+            "| :---: | :---: | :---:  |",
+            "| [Day 1](./src/bin/2023-01.rs) | `10ms` | `20ms` |",
+            "| [Day 2](./src/bin/2023-02.rs) | `30ms` | `40ms` |",
+            "",
+            "**Total: 100.00ms**",
+            MARKER,
+        ]
+        .join("\n");
+
+        let timings = vec![Timings {
+            day: day!(2),
+            parse: Some("2.0ms".into()),
+            part_1: Some("5.0ms".into()),
+            part_2: Some("3.0ms".into()),
+            part_1_stats: None,
+            part_2_stats: None,
+            total_nanos: 1e+7,
+        }];
+        update_content(&mut s, year!(2023), &[day!(2)], timings).unwrap();
+
+        assert!(s.contains("| Day | Parse | Part 1 | Part 2 |"));
+        assert!(s.contains("| [Day 1](./src/bin/2023-01.rs) | `-` | `10ms` | `20ms` |"));
+        assert!(s.contains("| [Day 2](./src/bin/2023-02.rs) | `2.0ms` | `5.0ms` | `3.0ms` |"));
+        assert!(s.contains("**Total: 40.00ms**"));
+    }
+
+    #[test]
+    fn adds_placeholder_rows() {
+        let mut s = format!("foo\n{}\n{}\nbaz", MARKER, MARKER);
+        update_content(
+            &mut s,
+            year!(2023),
+            &all_days().collect::<Vec<_>>(),
+            get_mock_timings(),
+        )
+        .unwrap();
+
+        assert!(add_row_to_content(&mut s, year!(2023), day!(3)).unwrap());
+        assert!(!add_row_to_content(&mut s, year!(2023), day!(3)).unwrap());
+        assert!(!add_row_to_content(&mut s, year!(2023), day!(1)).unwrap());
+
+        let rows: Vec<&str> = s.lines().filter(|l| l.starts_with("| [Day")).collect();
+        assert_eq!(
+            rows,
+            [
+                "| [Day 1](./src/bin/2023-01.rs) | `-` | `10ms` | `20ms` |",
+                "| [Day 2](./src/bin/2023-02.rs) | `-` | `30ms` | `40ms` |",
+                "| [Day 3](./src/bin/2023-03.rs) | `-` | `-` | `-` |",
+                "| [Day 4](./src/bin/2023-04.rs) | `-` | `40ms` | `50ms` |",
+            ]
+        );
+        assert!(s.contains("**Total: 190.00ms**"));
+    }
 }
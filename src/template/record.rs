@@ -0,0 +1,295 @@
+/// Machine-readable results of a solution part, exchanged between solution binaries and the template commands.
+use std::fmt::Display;
+use std::str::FromStr;
+use std::time::Duration;
+
+use crate::template::json::{self, Value};
+use crate::template::stats::BenchStats;
+use crate::{Day, Year};
+
+/// Output format of `solve` and `all`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum OutputFormat {
+    /// Human-readable, ANSI-decorated text.
+    #[default]
+    Text,
+    /// One JSON record per line.
+    Json,
+}
+
+impl FromStr for OutputFormat {
+    type Err = OutputFormatFromStrError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(OutputFormat::Text),
+            "json" => Ok(OutputFormat::Json),
+            _ => Err(OutputFormatFromStrError),
+        }
+    }
+}
+
+impl Display for OutputFormat {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            OutputFormat::Text => f.write_str("text"),
+            OutputFormat::Json => f.write_str("json"),
+        }
+    }
+}
+
+/// An error which can be returned when parsing an [`OutputFormat`].
+#[derive(Debug)]
+pub struct OutputFormatFromStrError;
+
+impl std::error::Error for OutputFormatFromStrError {}
+
+impl Display for OutputFormatFromStrError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("expecting one of `text`, `json`")
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Outcome of running a solution part.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    /// The part returned an answer.
+    Solved,
+    /// The part returned [`None`].
+    Unsolved,
+    /// The part returned an error or panicked.
+    Failed,
+}
+
+impl Status {
+    fn as_str(self) -> &'static str {
+        match self {
+            Status::Solved => "solved",
+            Status::Unsolved => "unsolved",
+            Status::Failed => "failed",
+        }
+    }
+
+    fn parse(s: &str) -> Option<Self> {
+        match s {
+            "solved" => Some(Status::Solved),
+            "unsolved" => Some(Status::Unsolved),
+            "failed" => Some(Status::Failed),
+            _ => None,
+        }
+    }
+}
+
+/// The result of a single solution part, as emitted by `--format json`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PartRecord {
+    pub year: Year,
+    pub day: Day,
+    pub part: u8,
+    pub answer: Option<String>,
+    pub duration: Duration,
+    pub samples: u128,
+    /// Benchmark statistics, only present for `--time` runs.
+    pub stats: Option<BenchStats>,
+    pub status: Status,
+    /// The error of a failed part.
+    pub error: Option<String>,
+    /// Duration of the parse step shared by both parts, for solutions that declare one.
+    pub parse_duration: Option<Duration>,
+}
+
+impl PartRecord {
+    /// Serializes the record as a single-line JSON object.
+    #[must_use]
+    pub fn to_json(&self) -> String {
+        let stat = |f: fn(&BenchStats) -> Duration| {
+            Value::from(self.stats.as_ref().map(|s| f(s).as_nanos()))
+        };
+
+        json::to_object(&[
+            ("year", self.year.into_inner().into()),
+            ("day", self.day.into_inner().into()),
+            ("part", self.part.into()),
+            ("answer", self.answer.clone().into()),
+            ("duration_nanos", self.duration.as_nanos().into()),
+            ("samples", self.samples.into()),
+            ("min_nanos", stat(|s| s.min)),
+            ("median_nanos", stat(|s| s.median)),
+            ("p95_nanos", stat(|s| s.p95)),
+            ("max_nanos", stat(|s| s.max)),
+            ("stddev_nanos", stat(|s| s.stddev)),
+            ("outliers", self.stats.map(|s| s.outliers).into()),
+            ("status", self.status.as_str().into()),
+            ("error", self.error.clone().into()),
+            (
+                "parse_nanos",
+                self.parse_duration.map(|d| d.as_nanos()).into(),
+            ),
+        ])
+    }
+
+    /// Parses a record from a line of output. Returns [`None`] for lines that are not records.
+    #[must_use]
+    pub fn from_json(line: &str) -> Option<Self> {
+        let fields = json::parse_object(line)?;
+
+        let year = Year::new(u16::try_from(fields.get("year")?.as_u128()?).ok()?)?;
+        let day = Day::new(u8::try_from(fields.get("day")?.as_u128()?).ok()?)?;
+        let part = u8::try_from(fields.get("part")?.as_u128()?).ok()?;
+        let answer = match fields.get("answer")? {
+            Value::Null => None,
+            value => Some(value.as_str()?.to_string()),
+        };
+        let nanos = |key: &str| {
+            let nanos = fields.get(key)?.as_u128()?;
+            u64::try_from(nanos).ok().map(Duration::from_nanos)
+        };
+        let duration = nanos("duration_nanos")?;
+        let samples = fields.get("samples")?.as_u128()?;
+        let status = Status::parse(fields.get("status")?.as_str()?)?;
+        let error = fields.get("error").and_then(Value::as_str).map(Into::into);
+        let parse_duration = nanos("parse_nanos");
+
+        // statistics are absent for runs that were not benched.
+        let stats = (|| {
+            Some(BenchStats {
+                samples,
+                mean: duration,
+                min: nanos("min_nanos")?,
+                median: nanos("median_nanos")?,
+                p95: nanos("p95_nanos")?,
+                max: nanos("max_nanos")?,
+                stddev: nanos("stddev_nanos")?,
+                outliers: fields.get("outliers")?.as_u128()?,
+            })
+        })();
+
+        Some(Self {
+            year,
+            day,
+            part,
+            answer,
+            duration,
+            samples,
+            stats,
+            status,
+            error,
+            parse_duration,
+        })
+    }
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::time::Duration;
+
+    use super::{PartRecord, Status};
+    use crate::template::stats::BenchStats;
+    use crate::{day, year};
+
+    #[test]
+    fn round_trip() {
+        let record = PartRecord {
+            year: year!(2023),
+            day: day!(5),
+            part: 2,
+            answer: Some("46".into()),
+            duration: Duration::from_nanos(74130),
+            samples: 1,
+            stats: None,
+            status: Status::Solved,
+            error: None,
+            parse_duration: None,
+        };
+
+        let line = record.to_json();
+        assert_eq!(
+            line,
+            r#"{"year":2023,"day":5,"part":2,"answer":"46","duration_nanos":74130,"samples":1,"min_nanos":null,"median_nanos":null,"p95_nanos":null,"max_nanos":null,"stddev_nanos":null,"outliers":null,"status":"solved","error":null,"parse_nanos":null}"#
+        );
+        assert_eq!(PartRecord::from_json(&line), Some(record));
+    }
+
+    #[test]
+    fn round_trip_stats() {
+        let stats = BenchStats {
+            samples: 100,
+            mean: Duration::from_nanos(74130),
+            min: Duration::from_nanos(70000),
+            median: Duration::from_nanos(74000),
+            p95: Duration::from_nanos(80000),
+            max: Duration::from_nanos(120000),
+            stddev: Duration::from_nanos(3200),
+            outliers: 3,
+        };
+        let record = PartRecord {
+            year: year!(2023),
+            day: day!(5),
+            part: 1,
+            answer: Some("35".into()),
+            duration: stats.mean,
+            samples: stats.samples,
+            stats: Some(stats),
+            status: Status::Solved,
+            error: None,
+            parse_duration: Some(Duration::from_nanos(31200)),
+        };
+
+        assert_eq!(PartRecord::from_json(&record.to_json()), Some(record));
+    }
+
+    #[test]
+    fn round_trip_failure() {
+        let record = PartRecord {
+            year: year!(2023),
+            day: day!(1),
+            part: 1,
+            answer: None,
+            duration: Duration::from_nanos(1200),
+            samples: 1,
+            stats: None,
+            status: Status::Failed,
+            error: Some("invalid digit\n  caused by: empty line".into()),
+            parse_duration: None,
+        };
+
+        assert_eq!(PartRecord::from_json(&record.to_json()), Some(record));
+    }
+
+    #[test]
+    fn ignores_prose() {
+        assert_eq!(
+            PartRecord::from_json("Part 1: 0 (74.13ns @ 100000 samples)"),
+            None
+        );
+        assert_eq!(PartRecord::from_json(r#"{"day":1}"#), None);
+    }
+
+    #[test]
+    fn tells_years_apart() {
+        let record = |year| PartRecord {
+            year,
+            day: day!(1),
+            part: 1,
+            answer: Some("142".into()),
+            duration: Duration::from_nanos(1200),
+            samples: 1,
+            stats: None,
+            status: Status::Solved,
+            error: None,
+            parse_duration: None,
+        };
+
+        let line = record(year!(2022)).to_json();
+        assert_eq!(
+            PartRecord::from_json(&line).map(|r| r.year),
+            Some(year!(2022))
+        );
+        assert_ne!(
+            PartRecord::from_json(&line),
+            PartRecord::from_json(&record(year!(2023)).to_json())
+        );
+    }
+}
@@ -0,0 +1,54 @@
+/// Registry of the solutions in `src/bin`. With the `registry` feature, the build script compiles every
+/// solution into the library as well, which allows running them in-process without spawning one binary per day.
+/// Without the feature, the registry is empty.
+use std::time::Duration;
+
+use crate::template::record::PartRecord;
+use crate::{Day, Year};
+
+/// Type-erased entry point of a solution, created by the [`solution!`](crate::solution) macro.
+#[derive(Clone, Copy)]
+pub struct Solution {
+    pub year: Year,
+    pub day: Day,
+    /// Runs both parts on an input and returns their records, benching them if a time budget is passed.
+    pub run: fn(&str, Option<Duration>) -> Vec<PartRecord>,
+}
+
+include!(concat!(env!("OUT_DIR"), "/solutions.rs"));
+
+/// Whether the solutions are compiled into the library.
+pub const ENABLED: bool = cfg!(feature = "registry");
+
+/// Returns all registered solutions, ordered by year and day.
+#[must_use]
+pub fn all() -> &'static [Solution] {
+    SOLUTIONS
+}
+
+/// Returns the solution of a day, if there is one.
+#[must_use]
+pub fn get(year: Year, day: Day) -> Option<&'static Solution> {
+    SOLUTIONS.iter().find(|s| s.year == year && s.day == day)
+}
+
+#[cfg(all(feature = "test_lib", feature = "registry"))]
+mod tests {
+    use super::{all, get};
+    use crate::template::get_path_for_bin;
+    use crate::{all_days, Year};
+    use std::path::Path;
+
+    #[test]
+    fn registers_all_solutions() {
+        assert!(all()
+            .windows(2)
+            .all(|w| (w[0].year, w[0].day) < (w[1].year, w[1].day)));
+
+        let year = Year::from_env().unwrap();
+        for day in all_days() {
+            let exists = Path::new(&get_path_for_bin(year, day)).exists();
+            assert_eq!(get(year, day).map(|s| s.day), exists.then_some(day));
+        }
+    }
+}
@@ -1,67 +1,442 @@
 /// Encapsulates code that interacts with solution functions.
-use crate::template::{aoc_cli, ANSI_ITALIC, ANSI_RESET};
-use crate::Day;
+use crate::template::record::{OutputFormat, PartRecord, Status};
+use crate::template::stats::BenchStats;
+use crate::template::{
+    answers,
+    aoc_client::{self, Verdict},
+    ANSI_ITALIC, ANSI_RESET,
+};
+use crate::{Day, Year};
+use std::borrow::Borrow;
+use std::cell::{Cell, RefCell};
+use std::error::Error;
 use std::fmt::Display;
-use std::io::{stdout, Write};
-use std::process::Output;
+use std::io::{stdin, stdout, Read, Write};
+use std::panic::{self, AssertUnwindSafe};
+use std::path::Path;
+use std::str::FromStr;
+use std::sync::Once;
 use std::time::{Duration, Instant};
-use std::{cmp, env, process};
+use std::{env, fs, io, process};
 
 use super::ANSI_BOLD;
 
-pub fn run_part<I: Clone, T: Display>(func: impl Fn(I) -> Option<T>, input: I, day: Day, part: u8) {
-    let part_str = format!("Part {part}");
+/// Outcome of running a solution part.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Outcome {
+    Solved(String),
+    Unsolved,
+    /// The part returned an error or panicked, with a description of what went wrong.
+    Failed(String),
+}
 
-    let (result, duration, samples) =
-        run_timed(func, input, |result| print_result(result, &part_str, ""));
+/// Values a solution part can return: an [`Option`] of an answer, or a [`Result`] of an answer.
+/// Errors are reported together with their chain of sources.
+pub trait PartOutput {
+    fn into_outcome(self) -> Outcome;
+}
 
-    print_result(&result, &part_str, &format_duration(&duration, samples));
+impl PartOutput for Outcome {
+    fn into_outcome(self) -> Outcome {
+        self
+    }
+}
 
-    if let Some(result) = result {
-        submit_result(result, day, part);
+impl<T: Display> PartOutput for Option<T> {
+    fn into_outcome(self) -> Outcome {
+        match self {
+            Some(answer) => Outcome::Solved(answer.to_string()),
+            None => Outcome::Unsolved,
+        }
     }
 }
 
-/// Run a solution part. The behavior differs depending on whether we are running a release or debug build:
-///  1. in debug, the function is executed once.
-///  2. in release, the function is benched (approx. 1 second of execution time or 10 samples, whatever take longer.)
-fn run_timed<I: Clone, T>(
-    func: impl Fn(I) -> T,
+impl<T: Display, E: Into<Box<dyn Error>>> PartOutput for Result<T, E> {
+    fn into_outcome(self) -> Outcome {
+        match self {
+            Ok(answer) => Outcome::Solved(answer.to_string()),
+            Err(e) => Outcome::Failed(format_error(&*e.into())),
+        }
+    }
+}
+
+/// Formats an error followed by its sources, one per line.
+fn format_error(error: &dyn Error) -> String {
+    let mut message = error.to_string();
+    let mut source = error.source();
+
+    while let Some(e) = source {
+        message.push_str(&format!("\n  caused by: {e}"));
+        source = e.source();
+    }
+
+    message
+}
+
+thread_local! {
+    static CATCHING_PANIC: Cell<bool> = const { Cell::new(false) };
+    static PANIC_MESSAGE: RefCell<Option<String>> = const { RefCell::new(None) };
+}
+
+/// Runs `f`, turning a panic into an error message that includes its location.
+/// The default panic output is suppressed while `f` runs.
+fn catch_panic<R>(f: impl FnOnce() -> R) -> Result<R, String> {
+    static HOOK: Once = Once::new();
+
+    HOOK.call_once(|| {
+        let default_hook = panic::take_hook();
+        panic::set_hook(Box::new(move |info| {
+            if !CATCHING_PANIC.get() {
+                return default_hook(info);
+            }
+
+            let payload = info
+                .payload()
+                .downcast_ref::<&str>()
+                .map(ToString::to_string)
+                .or_else(|| info.payload().downcast_ref::<String>().cloned())
+                .unwrap_or_default();
+            // solutions compiled into the library by the build script have absolute paths.
+            let message = match info.location() {
+                Some(location) => {
+                    let file = Path::new(location.file());
+                    let file = file
+                        .strip_prefix(env!("CARGO_MANIFEST_DIR"))
+                        .unwrap_or(file);
+                    format!(
+                        "panicked at {}:{}:{}: {payload}",
+                        file.display(),
+                        location.line(),
+                        location.column()
+                    )
+                }
+                None => format!("panicked: {payload}"),
+            };
+            PANIC_MESSAGE.set(Some(message));
+        }));
+    });
+
+    CATCHING_PANIC.set(true);
+    let result = panic::catch_unwind(AssertUnwindSafe(f));
+    CATCHING_PANIC.set(false);
+
+    result.map_err(|_| PANIC_MESSAGE.take().unwrap_or_else(|| "panicked".into()))
+}
+
+/// Runs a solution part once, catching panics.
+fn run_once<I, R: PartOutput>(func: &impl Fn(I) -> R, input: I) -> (Outcome, Duration) {
+    let timer = Instant::now();
+    let result = catch_panic(|| func(input));
+    let elapsed = timer.elapsed();
+
+    let outcome = result.map_or_else(Outcome::Failed, PartOutput::into_outcome);
+    (outcome, elapsed)
+}
+
+pub fn run_part<I: Clone, R: PartOutput>(
+    func: impl Fn(I) -> R,
     input: I,
-    hook: impl Fn(&T),
-) -> (T, Duration, u128) {
+    year: Year,
+    day: Day,
+    part: u8,
+) {
+    print_part(func, input, year, day, part, None);
+}
+
+/// Runs a solution that parses its input once in a shared `parse` step before running both parts on the result.
+/// Parts may take any borrowed form of the parsed value, e.g. `&[T]` for a `Vec<T>`.
+/// The parse time is reported separately from the time of the parts.
+pub fn run_parsed<P, T1, T2, R1, R2>(
+    parse: impl Fn(&str) -> P,
+    part_one: impl Fn(&T1) -> R1,
+    part_two: impl Fn(&T2) -> R2,
+    input: &str,
+    year: Year,
+    day: Day,
+) where
+    P: Borrow<T1> + Borrow<T2>,
+    T1: ?Sized,
+    T2: ?Sized,
+    R1: PartOutput,
+    R2: PartOutput,
+{
+    let format = output_format();
+
+    if format == OutputFormat::Text {
+        print_parse("");
+    }
+
+    let (parsed, duration) = run_parse(&parse, input, bench_budget(), format == OutputFormat::Text);
+
+    if format == OutputFormat::Text {
+        print_parse(&format_duration(&duration, None));
+    }
+
+    match parsed {
+        Ok(parsed) => {
+            print_part(&part_one, parsed.borrow(), year, day, 1, Some(duration));
+            print_part(&part_two, parsed.borrow(), year, day, 2, Some(duration));
+        }
+        Err(error) => {
+            for part in [1, 2] {
+                let outcome = parse_failure(&error);
+                match format {
+                    OutputFormat::Text => print_result(
+                        &outcome,
+                        &format!("Part {part}"),
+                        &format_duration(&Duration::ZERO, None),
+                    ),
+                    OutputFormat::Json => {
+                        let mut record = to_record(&outcome, year, day, part, Duration::ZERO, None);
+                        record.parse_duration = Some(duration);
+                        println!("{}", record.to_json());
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Runs a solution part and prints its result, submitting it if requested.
+fn print_part<I: Clone, R: PartOutput>(
+    func: impl Fn(I) -> R,
+    input: I,
+    year: Year,
+    day: Day,
+    part: u8,
+    parse_duration: Option<Duration>,
+) {
+    let format = output_format();
+    let part_str = format!("Part {part}");
+
+    let (outcome, duration, stats) = run_timed(func, input, format, |outcome| {
+        print_result(outcome, &part_str, "");
+    });
+
+    match format {
+        OutputFormat::Text => print_result(
+            &outcome,
+            &part_str,
+            &format_duration(&duration, stats.as_ref()),
+        ),
+        OutputFormat::Json => {
+            let mut record = to_record(&outcome, year, day, part, duration, stats);
+            record.parse_duration = parse_duration;
+            println!("{}", record.to_json());
+        }
+    }
+
+    if let Outcome::Solved(answer) = outcome {
+        submit_result(&answer, year, day, part);
+    }
+}
+
+/// Runs both parts of a solution without printing anything and returns their records.
+/// If a time budget is passed, the parts are benched (see [`bench`]).
+pub fn run_records<R1: PartOutput, R2: PartOutput>(
+    part_one: impl Fn(&str) -> R1,
+    part_two: impl Fn(&str) -> R2,
+    input: &str,
+    year: Year,
+    day: Day,
+    bench_budget: Option<Duration>,
+) -> Vec<PartRecord> {
+    vec![
+        run_record(part_one, input, year, day, 1, bench_budget),
+        run_record(part_two, input, year, day, 2, bench_budget),
+    ]
+}
+
+/// Like [`run_records`], for solutions with a shared parse step (see [`run_parsed`]).
+pub fn run_parsed_records<P, T1, T2, R1, R2>(
+    parse: impl Fn(&str) -> P,
+    part_one: impl Fn(&T1) -> R1,
+    part_two: impl Fn(&T2) -> R2,
+    input: &str,
+    year: Year,
+    day: Day,
+    bench_budget: Option<Duration>,
+) -> Vec<PartRecord>
+where
+    P: Borrow<T1> + Borrow<T2>,
+    T1: ?Sized,
+    T2: ?Sized,
+    R1: PartOutput,
+    R2: PartOutput,
+{
+    let (parsed, duration) = run_parse(&parse, input, bench_budget, false);
+
+    let mut records = match parsed {
+        Ok(parsed) => vec![
+            run_record(&part_one, parsed.borrow(), year, day, 1, bench_budget),
+            run_record(&part_two, parsed.borrow(), year, day, 2, bench_budget),
+        ],
+        Err(error) => [1, 2]
+            .into_iter()
+            .map(|part| {
+                to_record(
+                    &parse_failure(&error),
+                    year,
+                    day,
+                    part,
+                    Duration::ZERO,
+                    None,
+                )
+            })
+            .collect(),
+    };
+
+    for record in &mut records {
+        record.parse_duration = Some(duration);
+    }
+    records
+}
+
+/// Runs the parse step of a solution once, catching panics, and benches it if a time budget is passed.
+fn run_parse<P>(
+    parse: &impl Fn(&str) -> P,
+    input: &str,
+    bench_budget: Option<Duration>,
+    show_progress: bool,
+) -> (Result<P, String>, Duration) {
     let timer = Instant::now();
-    let result = func(input.clone());
+    let parsed = catch_panic(|| parse(input));
     let base_time = timer.elapsed();
 
-    hook(&result);
+    let stats = match parsed {
+        Ok(_) => bench_budget.and_then(|budget| bench(parse, input, budget, show_progress)),
+        Err(_) => None,
+    };
 
-    let run = if std::env::args().any(|x| x == "--time") {
-        bench(func, input, &base_time)
-    } else {
-        (base_time, 1)
+    (parsed, stats.map_or(base_time, |s| s.mean))
+}
+
+/// Outcome of a part whose input could not be parsed.
+fn parse_failure(error: &str) -> Outcome {
+    Outcome::Failed(format!("parse failed: {error}"))
+}
+
+/// Runs a solution part without printing anything and returns its record.
+/// If a time budget is passed, the part is benched (see [`bench`]).
+fn run_record<I: Clone, R: PartOutput>(
+    func: impl Fn(I) -> R,
+    input: I,
+    year: Year,
+    day: Day,
+    part: u8,
+    bench_budget: Option<Duration>,
+) -> PartRecord {
+    let (outcome, base_time) = run_once(&func, input.clone());
+
+    // failed parts are not benched.
+    let stats = match outcome {
+        Outcome::Failed(_) => None,
+        _ => bench_budget.and_then(|budget| bench(func, input, budget, false)),
+    };
+
+    to_record(
+        &outcome,
+        year,
+        day,
+        part,
+        stats.map_or(base_time, |s| s.mean),
+        stats,
+    )
+}
+
+fn to_record(
+    outcome: &Outcome,
+    year: Year,
+    day: Day,
+    part: u8,
+    duration: Duration,
+    stats: Option<BenchStats>,
+) -> PartRecord {
+    let (answer, status, error) = match outcome {
+        Outcome::Solved(answer) => (Some(answer.clone()), Status::Solved, None),
+        Outcome::Unsolved => (None, Status::Unsolved, None),
+        Outcome::Failed(error) => (None, Status::Failed, Some(error.clone())),
     };
 
-    (result, run.0, run.1)
+    PartRecord {
+        year,
+        day,
+        part,
+        answer,
+        duration,
+        samples: stats.map_or(1, |s| s.samples),
+        stats,
+        status,
+        error,
+        parse_duration: None,
+    }
 }
 
-fn bench<I: Clone, T>(func: impl Fn(I) -> T, input: I, base_time: &Duration) -> (Duration, u128) {
-    let mut stdout = stdout();
+/// Run a solution part. The behavior differs depending on whether the `--time` flag is passed:
+///  1. without it, the function is executed once.
+///  2. with it, the function is benched (see [`bench`]), unless it failed.
+///
+/// Intermediate output is only printed for the [`OutputFormat::Text`] format.
+fn run_timed<I: Clone, R: PartOutput>(
+    func: impl Fn(I) -> R,
+    input: I,
+    format: OutputFormat,
+    hook: impl Fn(&Outcome),
+) -> (Outcome, Duration, Option<BenchStats>) {
+    let (outcome, base_time) = run_once(&func, input.clone());
 
-    print!(" > {ANSI_ITALIC}benching{ANSI_RESET}");
-    let _ = stdout.flush();
+    if format == OutputFormat::Text {
+        hook(&outcome);
+    }
 
-    let bench_iterations = cmp::min(
-        10000,
-        cmp::max(
-            Duration::from_secs(1).as_nanos() / cmp::max(base_time.as_nanos(), 10),
-            10,
-        ),
-    );
+    let failed = matches!(outcome, Outcome::Failed(_));
+
+    match bench_budget() {
+        Some(budget) if !failed => {
+            let stats = bench(func, input, budget, format == OutputFormat::Text);
+            (outcome, stats.map_or(base_time, |s| s.mean), stats)
+        }
+        _ => (outcome, base_time, None),
+    }
+}
+
+/// Reads the time budget for benching if the `--time` flag was passed to the solution binary.
+fn bench_budget() -> Option<Duration> {
+    env::args()
+        .any(|x| x == "--time")
+        .then(|| arg_value("--bench-time").map_or(DEFAULT_BENCH_BUDGET, Duration::from_millis))
+}
+
+/// Default time budget of a benchmark, can be overridden with `--bench-time <ms>`.
+pub const DEFAULT_BENCH_BUDGET: Duration = Duration::from_secs(1);
+/// Share of the time budget spent on warm-up iterations that are not measured.
+const WARMUP_FRACTION: u32 = 10;
+const MIN_SAMPLES: usize = 10;
+const MAX_SAMPLES: usize = 100_000;
+
+/// Bench a solution part: after a warm-up phase, the function is sampled until the time budget is spent,
+/// taking at least 10 and at most 100.000 samples.
+fn bench<I: Clone, T>(
+    func: impl Fn(I) -> T,
+    input: I,
+    budget: Duration,
+    show_progress: bool,
+) -> Option<BenchStats> {
+    if show_progress {
+        let mut stdout = stdout();
+        print!(" > {ANSI_ITALIC}benching{ANSI_RESET}");
+        let _ = stdout.flush();
+    }
+
+    let warmup = Instant::now();
+    while warmup.elapsed() < budget / WARMUP_FRACTION {
+        func(input.clone());
+    }
 
     let mut timers: Vec<Duration> = vec![];
+    let bench = Instant::now();
 
-    for _ in 0..bench_iterations {
+    while timers.len() < MAX_SAMPLES && (timers.len() < MIN_SAMPLES || bench.elapsed() < budget) {
         // need a clone here to make the borrow checker happy.
         let cloned = input.clone();
         let timer = Instant::now();
@@ -69,35 +444,107 @@ fn bench<I: Clone, T>(func: impl Fn(I) -> T, input: I, base_time: &Duration) ->
         timers.push(timer.elapsed());
     }
 
-    (
-        #[allow(clippy::cast_possible_truncation)]
-        Duration::from_nanos(average_duration(&timers) as u64),
-        bench_iterations,
-    )
+    BenchStats::from_samples(&timers)
 }
 
-fn average_duration(numbers: &[Duration]) -> u128 {
-    numbers
-        .iter()
-        .map(std::time::Duration::as_nanos)
-        .sum::<u128>()
-        / numbers.len() as u128
+fn format_duration(duration: &Duration, stats: Option<&BenchStats>) -> String {
+    match stats {
+        None => format!(" ({duration:.1?})"),
+        Some(s) => format!(
+            " ({:.1?} ± {:.1?} @ {} samples, min {:.1?}, p50 {:.1?}, p95 {:.1?}, max {:.1?}, {} outliers)",
+            s.mean, s.stddev, s.samples, s.min, s.median, s.p95, s.max, s.outliers
+        ),
+    }
+}
+
+/// Prints a record received from a solution binary in the human-readable format.
+/// The time of a shared parse step is printed before the first part.
+pub fn print_record(record: &PartRecord) {
+    if let (1, Some(duration)) = (record.part, record.parse_duration) {
+        print_parse(&format_duration(&duration, None));
+    }
+
+    let outcome = match (record.status, &record.answer, &record.error) {
+        (Status::Solved, Some(answer), _) => Outcome::Solved(answer.clone()),
+        (Status::Failed, _, error) => Outcome::Failed(error.clone().unwrap_or_default()),
+        _ => Outcome::Unsolved,
+    };
+
+    print_result(
+        &outcome,
+        &format!("Part {}", record.part),
+        &format_duration(&record.duration, record.stats.as_ref()),
+    );
+}
+
+/// Reads the `--format` argument passed to the solution binary.
+fn output_format() -> OutputFormat {
+    arg_value("--format").unwrap_or_default()
 }
 
-fn format_duration(duration: &Duration, samples: u128) -> String {
-    if samples == 1 {
-        format!(" ({duration:.1?})")
+/// Reads the value following the argument `name` passed to the solution binary.
+fn arg_value<T: FromStr>(name: &str) -> Option<T> {
+    let args: Vec<String> = env::args().collect();
+
+    args.iter()
+        .position(|x| x == name)
+        .and_then(|i| args.get(i + 1))
+        .and_then(|x| x.parse().ok())
+}
+
+/// Reads the input of the solution binary: the file passed with `--input <path>`, stdin for `--input -`,
+/// the example of the day for `--example` and the puzzle input otherwise.
+#[must_use]
+pub fn read_input(year: Year, day: Day) -> String {
+    let (source, input) = match arg_value::<String>("--input") {
+        Some(path) if path == "-" => {
+            let mut input = String::new();
+            (
+                "stdin".into(),
+                stdin().read_to_string(&mut input).map(|_| input),
+            )
+        }
+        Some(path) => {
+            let input = fs::read_to_string(&path);
+            (format!("\"{path}\""), input)
+        }
+        None if env::args().any(|x| x == "--example") => {
+            let path = format!("data/{year}/examples/{day}.txt");
+            let input = fs::read_to_string(&path);
+            (format!("\"{path}\""), input)
+        }
+        None => (
+            format!("\"data/{year}/inputs/{day}.txt\""),
+            super::read_input(year, day),
+        ),
+    };
+
+    input.unwrap_or_else(|e: io::Error| {
+        eprintln!("Could not read the input from {source}: {e}");
+        process::exit(1);
+    })
+}
+
+/// Returns whether the solution binary runs against another input than the puzzle input of the day.
+fn custom_input() -> bool {
+    env::args().any(|x| x == "--input" || x == "--example")
+}
+
+fn print_parse(duration_str: &str) {
+    if duration_str.is_empty() {
+        print!("Parse:");
     } else {
-        format!(" ({duration:.1?} @ {samples} samples)")
+        print!("\r");
+        println!("Parse:{duration_str}             ");
     }
 }
 
-fn print_result<T: Display>(result: &Option<T>, part: &str, duration_str: &str) {
+fn print_result(outcome: &Outcome, part: &str, duration_str: &str) {
     let is_intermediate_result = duration_str.is_empty();
 
-    match result {
-        Some(result) => {
-            if result.to_string().contains('\n') {
+    match outcome {
+        Outcome::Solved(result) => {
+            if result.contains('\n') {
                 let str = format!("{part}: ▼ {duration_str}");
                 if is_intermediate_result {
                     print!("{str}");
@@ -116,7 +563,7 @@ fn print_result<T: Display>(result: &Option<T>, part: &str, duration_str: &str)
                 }
             }
         }
-        None => {
+        Outcome::Unsolved => {
             if is_intermediate_result {
                 print!("{part}: ✖");
             } else {
@@ -124,23 +571,35 @@ fn print_result<T: Display>(result: &Option<T>, part: &str, duration_str: &str)
                 println!("{part}: ✖             ");
             }
         }
+        Outcome::Failed(error) => {
+            if is_intermediate_result {
+                print!("{part}: ✖");
+            } else {
+                print!("\r");
+                println!("{part}: ✖ {ANSI_ITALIC}failed{ANSI_RESET}: {error}");
+            }
+        }
     }
 }
 
-/// Parse the arguments passed to `solve` and try to submit one part of the solution if:
-///  1. we are in `--release` mode.
-///  2. aoc-cli is installed.
-fn submit_result<T: Display>(
-    result: T,
+/// Parse the arguments passed to `solve` and try to submit one part of the solution if `--submit <part>` is passed.
+fn submit_result(
+    result: &str,
+    year: Year,
     day: Day,
     part: u8,
-) -> Option<Result<Output, aoc_cli::AocCommandError>> {
+) -> Option<Result<Verdict, aoc_client::AocClientError>> {
     let args: Vec<String> = env::args().collect();
 
     if !args.contains(&"--submit".into()) {
         return None;
     }
 
+    if custom_input() {
+        eprintln!("Not submitting: answers are only submitted for the puzzle input of the day.");
+        return None;
+    }
+
     if args.len() < 3 {
         eprintln!("Unexpected command-line input. Format: cargo solve 1 --submit 1");
         process::exit(1);
@@ -157,11 +616,123 @@ fn submit_result<T: Display>(
         return None;
     }
 
-    if aoc_cli::check().is_err() {
-        eprintln!("command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it.");
+    let Some(answer) = answers::normalize(result) else {
+        eprintln!(
+            "Not submitting: the answer spans multiple lines, read it and submit it by hand."
+        );
+        return None;
+    };
+
+    let submissions = answers::load(year, day).unwrap_or_else(|e| {
+        eprintln!("Could not read previous submissions: {e:?}");
         process::exit(1);
+    });
+
+    match answers::check(&submissions, part, &answer) {
+        answers::Guard::Allow => {}
+        answers::Guard::Warn(reason) => eprintln!("Warning: {reason}"),
+        answers::Guard::Refuse(reason) => {
+            eprintln!("Not submitting: {reason}");
+            return None;
+        }
+    }
+
+    println!("Submitting result...");
+    let verdict = aoc_client::submit(year, day, part, &answer);
+
+    match &verdict {
+        Ok(verdict) => {
+            let submission = answers::Submission::new(part, &answer, verdict.clone());
+            if let Err(e) = answers::record(year, day, &submission) {
+                eprintln!("Could not record submission: {e:?}");
+            }
+        }
+        Err(e) => eprintln!("failed to submit result: {e}"),
     }
 
-    println!("Submitting result via aoc-cli...");
-    Some(aoc_cli::submit(day, part, &result.to_string()))
+    Some(verdict)
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{catch_panic, run_record, Outcome, PartOutput};
+    use crate::template::record::Status;
+    use crate::{day, year};
+    use std::fmt::{self, Display};
+
+    #[derive(Debug)]
+    struct ParseError(Option<std::num::ParseIntError>);
+
+    impl Display for ParseError {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.write_str("invalid input")
+        }
+    }
+
+    impl std::error::Error for ParseError {
+        fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+            self.0.as_ref().map(|e| e as _)
+        }
+    }
+
+    #[test]
+    fn converts_part_outputs() {
+        assert_eq!(Some(42u64).into_outcome(), Outcome::Solved("42".into()));
+        assert_eq!(None::<i64>.into_outcome(), Outcome::Unsolved);
+        assert_eq!(
+            Ok::<_, String>("#.\n.#").into_outcome(),
+            Outcome::Solved("#.\n.#".into())
+        );
+        assert_eq!(
+            Err::<u32, _>("no start").into_outcome(),
+            Outcome::Failed("no start".into())
+        );
+    }
+
+    #[test]
+    fn reports_error_chains() {
+        let source = "x".parse::<u32>().unwrap_err();
+        let outcome = Err::<u32, _>(ParseError(Some(source))).into_outcome();
+        assert_eq!(
+            outcome,
+            Outcome::Failed("invalid input\n  caused by: invalid digit found in string".into())
+        );
+    }
+
+    #[test]
+    fn catches_panics() {
+        let error = catch_panic(|| panic!("not found a number")).unwrap_err();
+        assert!(error.starts_with("panicked at src/template/runner.rs:"));
+        assert!(error.ends_with(": not found a number"));
+        assert_eq!(catch_panic(|| 1), Ok(1));
+    }
+
+    #[test]
+    fn records_failed_parts() {
+        let record = run_record(
+            |input: &str| Some(input.parse::<u32>().unwrap()),
+            "x",
+            year!(2023),
+            day!(1),
+            1,
+            None,
+        );
+        assert_eq!(record.status, Status::Failed);
+        assert!(record.answer.is_none());
+        assert!(record
+            .error
+            .unwrap()
+            .contains("ParseIntError { kind: InvalidDigit }"));
+
+        let record = run_record(
+            |input: &str| input.parse::<u32>().ok(),
+            "x",
+            year!(2023),
+            day!(1),
+            2,
+            None,
+        );
+        assert_eq!(record.status, Status::Unsolved);
+        assert!(record.error.is_none());
+    }
 }
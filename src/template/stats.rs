@@ -0,0 +1,134 @@
+/// Summary statistics over benchmark samples.
+use std::time::Duration;
+
+/// Statistics of a benchmarked solution part.
+///
+/// Outliers are detected with Tukey's fences (1.5 × IQR beyond the quartiles).
+/// They are excluded from `mean` and `stddev`, while the order statistics (`min`, `median`, `p95`, `max`)
+/// are computed over all samples.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BenchStats {
+    pub samples: u128,
+    pub mean: Duration,
+    pub min: Duration,
+    pub median: Duration,
+    pub p95: Duration,
+    pub max: Duration,
+    pub stddev: Duration,
+    pub outliers: u128,
+}
+
+impl BenchStats {
+    /// Computes statistics over the given samples. Returns [`None`] if there are no samples.
+    #[must_use]
+    pub fn from_samples(samples: &[Duration]) -> Option<Self> {
+        if samples.is_empty() {
+            return None;
+        }
+
+        let mut sorted: Vec<u128> = samples.iter().map(Duration::as_nanos).collect();
+        sorted.sort_unstable();
+
+        let q1 = percentile(&sorted, 25.0);
+        let q3 = percentile(&sorted, 75.0);
+        let fence = (q3 - q1) * 3 / 2;
+        let lower = q1.saturating_sub(fence);
+        let upper = q3 + fence;
+
+        let retained: Vec<u128> = sorted
+            .iter()
+            .copied()
+            .filter(|x| (lower..=upper).contains(x))
+            .collect();
+
+        #[allow(clippy::cast_precision_loss)]
+        let mean = retained.iter().sum::<u128>() as f64 / retained.len() as f64;
+
+        #[allow(clippy::cast_precision_loss)]
+        let variance = retained
+            .iter()
+            .map(|&x| (x as f64 - mean).powi(2))
+            .sum::<f64>()
+            / retained.len() as f64;
+
+        Some(Self {
+            samples: sorted.len() as u128,
+            mean: from_nanos_f64(mean),
+            min: from_nanos(sorted[0]),
+            median: from_nanos(percentile(&sorted, 50.0)),
+            p95: from_nanos(percentile(&sorted, 95.0)),
+            max: from_nanos(sorted[sorted.len() - 1]),
+            stddev: from_nanos_f64(variance.sqrt()),
+            outliers: (sorted.len() - retained.len()) as u128,
+        })
+    }
+}
+
+/// Nearest-rank percentile of an ascending, non-empty slice.
+fn percentile(sorted: &[u128], p: f64) -> u128 {
+    #[allow(
+        clippy::cast_precision_loss,
+        clippy::cast_possible_truncation,
+        clippy::cast_sign_loss
+    )]
+    let rank = ((p / 100.0) * sorted.len() as f64).ceil() as usize;
+    sorted[rank.clamp(1, sorted.len()) - 1]
+}
+
+fn from_nanos(nanos: u128) -> Duration {
+    Duration::from_nanos(u64::try_from(nanos).unwrap_or(u64::MAX))
+}
+
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+fn from_nanos_f64(nanos: f64) -> Duration {
+    Duration::from_nanos(nanos.round() as u64)
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::time::Duration;
+
+    use super::BenchStats;
+
+    fn nanos(values: &[u64]) -> Vec<Duration> {
+        values.iter().map(|&x| Duration::from_nanos(x)).collect()
+    }
+
+    #[test]
+    fn empty_samples() {
+        assert_eq!(BenchStats::from_samples(&[]), None);
+    }
+
+    #[test]
+    fn single_sample() {
+        let stats = BenchStats::from_samples(&nanos(&[42])).unwrap();
+        assert_eq!(stats.samples, 1);
+        assert_eq!(stats.mean, Duration::from_nanos(42));
+        assert_eq!(stats.min, Duration::from_nanos(42));
+        assert_eq!(stats.max, Duration::from_nanos(42));
+        assert_eq!(stats.stddev, Duration::ZERO);
+        assert_eq!(stats.outliers, 0);
+    }
+
+    #[test]
+    fn order_statistics() {
+        let samples: Vec<u64> = (1..=100).rev().collect();
+        let stats = BenchStats::from_samples(&nanos(&samples)).unwrap();
+        assert_eq!(stats.samples, 100);
+        assert_eq!(stats.min, Duration::from_nanos(1));
+        assert_eq!(stats.median, Duration::from_nanos(50));
+        assert_eq!(stats.p95, Duration::from_nanos(95));
+        assert_eq!(stats.max, Duration::from_nanos(100));
+        assert_eq!(stats.mean, Duration::from_nanos(51));
+        assert_eq!(stats.stddev, Duration::from_nanos(29));
+        assert_eq!(stats.outliers, 0);
+    }
+
+    #[test]
+    fn rejects_outliers() {
+        let stats = BenchStats::from_samples(&nanos(&[10, 11, 10, 12, 11, 10, 11, 1000])).unwrap();
+        assert_eq!(stats.outliers, 1);
+        assert_eq!(stats.max, Duration::from_nanos(1000));
+        assert_eq!(stats.mean, Duration::from_nanos(11));
+    }
+}
@@ -1,3 +1,12 @@
+pub mod grid;
+pub mod interval;
+mod parse;
+
+pub use parse::{
+    numbers, parse_int, parse_int_bytes, parse_numbers, FromNumbers, Integer, Numbers,
+    ParseIntError, ParseIntErrorKind,
+};
+
 pub fn byte_to_digit(b: u8) -> u32 {
     char::from(b).to_digit(10).unwrap_or_else(|| panic!("{} is digit", b))
 }
@@ -0,0 +1,642 @@
+//! Two-dimensional grids of cells, e.g. the character maps of many puzzles.
+//! Cells are addressed by [`Point`]s with `x` as the column and `y` as the row, growing downwards.
+
+use std::cmp::Ordering;
+use std::error::Error;
+use std::fmt::{self, Display};
+use std::iter;
+use std::ops::{Add, AddAssign, Index, IndexMut, Mul, Neg, Sub, SubAssign};
+use std::str::FromStr;
+
+mod regions;
+pub use regions::{Region, Span};
+
+/// Position on a grid. Points outside of a grid are valid, e.g. as neighbors of border cells.
+///
+/// Points are ordered in reading order, row by row.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Point {
+    pub x: isize,
+    pub y: isize,
+}
+
+/// Offsets of the eight neighbors of a point, clockwise starting upwards.
+const NEIGHBORS_8: [Point; 8] = [
+    Point::new(0, -1),
+    Point::new(1, -1),
+    Point::new(1, 0),
+    Point::new(1, 1),
+    Point::new(0, 1),
+    Point::new(-1, 1),
+    Point::new(-1, 0),
+    Point::new(-1, -1),
+];
+
+impl Point {
+    pub const ORIGIN: Point = Point::new(0, 0);
+
+    #[must_use]
+    pub const fn new(x: isize, y: isize) -> Self {
+        Self { x, y }
+    }
+
+    /// Returns the neighboring point in a direction.
+    #[must_use]
+    pub fn step(self, direction: Direction) -> Self {
+        self + direction.offset()
+    }
+
+    /// The four orthogonal neighbors, in the order of [`Direction::ALL`].
+    #[must_use]
+    pub fn neighbors4(self) -> [Point; 4] {
+        Direction::ALL.map(|direction| self.step(direction))
+    }
+
+    /// The eight neighbors including diagonals, clockwise starting upwards.
+    #[must_use]
+    pub fn neighbors8(self) -> [Point; 8] {
+        NEIGHBORS_8.map(|offset| self + offset)
+    }
+
+    #[must_use]
+    pub fn manhattan_distance(self, other: Point) -> usize {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y)
+    }
+}
+
+impl Ord for Point {
+    fn cmp(&self, other: &Self) -> Ordering {
+        (self.y, self.x).cmp(&(other.y, other.x))
+    }
+}
+
+impl PartialOrd for Point {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Add for Point {
+    type Output = Point;
+
+    fn add(self, other: Point) -> Point {
+        Point::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl Add<Direction> for Point {
+    type Output = Point;
+
+    fn add(self, direction: Direction) -> Point {
+        self.step(direction)
+    }
+}
+
+impl AddAssign for Point {
+    fn add_assign(&mut self, other: Point) {
+        *self = *self + other;
+    }
+}
+
+impl AddAssign<Direction> for Point {
+    fn add_assign(&mut self, direction: Direction) {
+        *self = self.step(direction);
+    }
+}
+
+impl Sub for Point {
+    type Output = Point;
+
+    fn sub(self, other: Point) -> Point {
+        Point::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl SubAssign for Point {
+    fn sub_assign(&mut self, other: Point) {
+        *self = *self - other;
+    }
+}
+
+impl Neg for Point {
+    type Output = Point;
+
+    fn neg(self) -> Point {
+        Point::new(-self.x, -self.y)
+    }
+}
+
+impl Mul<isize> for Point {
+    type Output = Point;
+
+    fn mul(self, factor: isize) -> Point {
+        Point::new(self.x * factor, self.y * factor)
+    }
+}
+
+/// One of the four orthogonal directions on a grid.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Direction {
+    /// All directions, clockwise starting upwards.
+    pub const ALL: [Direction; 4] = [
+        Direction::Up,
+        Direction::Right,
+        Direction::Down,
+        Direction::Left,
+    ];
+
+    /// Returns the offset of a step in this direction, e.g. `(0, -1)` for [`Direction::Up`].
+    #[must_use]
+    pub const fn offset(self) -> Point {
+        match self {
+            Direction::Up => Point::new(0, -1),
+            Direction::Right => Point::new(1, 0),
+            Direction::Down => Point::new(0, 1),
+            Direction::Left => Point::new(-1, 0),
+        }
+    }
+
+    #[must_use]
+    pub const fn turn_right(self) -> Self {
+        match self {
+            Direction::Up => Direction::Right,
+            Direction::Right => Direction::Down,
+            Direction::Down => Direction::Left,
+            Direction::Left => Direction::Up,
+        }
+    }
+
+    #[must_use]
+    pub const fn turn_left(self) -> Self {
+        self.opposite().turn_right()
+    }
+
+    #[must_use]
+    pub const fn opposite(self) -> Self {
+        self.turn_right().turn_right()
+    }
+}
+
+impl TryFrom<char> for Direction {
+    type Error = char;
+
+    /// Reads a direction from an arrow like `^` or a letter like `U`.
+    fn try_from(c: char) -> Result<Self, char> {
+        match c {
+            '^' | 'U' | 'N' => Ok(Direction::Up),
+            '>' | 'R' | 'E' => Ok(Direction::Right),
+            'v' | 'D' | 'S' => Ok(Direction::Down),
+            '<' | 'L' | 'W' => Ok(Direction::Left),
+            c => Err(c),
+        }
+    }
+}
+
+/// Error of parsing a grid whose rows differ in length.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ParseGridError {
+    /// Index of the first row whose length differs from the first row.
+    pub row: usize,
+    pub width: usize,
+    pub expected: usize,
+}
+
+impl Display for ParseGridError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "row {} has {} cells, expected {}",
+            self.row, self.width, self.expected
+        )
+    }
+}
+
+impl Error for ParseGridError {}
+
+/// Rectangular grid of cells, stored row by row.
+///
+/// ```
+/// # use advent_of_code::utils::grid::{Grid, Point};
+/// let grid: Grid<char> = "#.\n.#".parse().unwrap();
+/// assert_eq!(grid[Point::new(1, 1)], '#');
+/// assert_eq!(grid.neighbors4(Point::new(0, 0)).count(), 2);
+/// assert_eq!(grid.to_string(), "#.\n.#");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// Creates a grid with every cell set to `value`.
+    #[must_use]
+    pub fn new(width: usize, height: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            width,
+            height,
+            cells: vec![value; width * height],
+        }
+    }
+
+    /// Creates a grid from its cells, row by row.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number of cells is not a multiple of `width`.
+    #[must_use]
+    pub fn from_vec(width: usize, cells: Vec<T>) -> Self {
+        let height = cells.len().checked_div(width).unwrap_or(0);
+        assert_eq!(
+            width * height,
+            cells.len(),
+            "the number of cells is a multiple of the width"
+        );
+        Self {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    /// Parses a grid from lines of text, mapping each character to a cell.
+    /// Trailing empty lines are ignored.
+    pub fn parse_with(
+        input: &str,
+        mut cell: impl FnMut(char) -> T,
+    ) -> Result<Self, ParseGridError> {
+        let mut cells = vec![];
+        let mut width = None;
+        let mut height = 0;
+
+        for (row, line) in input.trim_end_matches(['\n', '\r']).lines().enumerate() {
+            let before = cells.len();
+            cells.extend(line.chars().map(&mut cell));
+            let line_width = cells.len() - before;
+
+            match width {
+                None => width = Some(line_width),
+                Some(expected) if expected != line_width => {
+                    return Err(ParseGridError {
+                        row,
+                        width: line_width,
+                        expected,
+                    });
+                }
+                Some(_) => {}
+            }
+
+            height += 1;
+        }
+
+        Ok(Self {
+            width: width.unwrap_or(0),
+            height,
+            cells,
+        })
+    }
+
+    #[must_use]
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    #[must_use]
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    fn index_of(&self, point: Point) -> Option<usize> {
+        let x = usize::try_from(point.x).ok().filter(|&x| x < self.width)?;
+        let y = usize::try_from(point.y).ok().filter(|&y| y < self.height)?;
+        Some(y * self.width + x)
+    }
+
+    /// Returns whether a point lies within the grid.
+    #[must_use]
+    pub fn contains(&self, point: Point) -> bool {
+        self.index_of(point).is_some()
+    }
+
+    #[must_use]
+    pub fn get(&self, point: Point) -> Option<&T> {
+        self.index_of(point).map(|i| &self.cells[i])
+    }
+
+    pub fn get_mut(&mut self, point: Point) -> Option<&mut T> {
+        self.index_of(point).map(|i| &mut self.cells[i])
+    }
+
+    /// Iterates the points of the grid in reading order.
+    pub fn points(&self) -> impl Iterator<Item = Point> {
+        let (width, height) = (self.width as isize, self.height as isize);
+        (0..height).flat_map(move |y| (0..width).map(move |x| Point::new(x, y)))
+    }
+
+    /// Iterates the cells of the grid together with their points, in reading order.
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.points().zip(&self.cells)
+    }
+
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = (Point, &mut T)> {
+        self.points().zip(&mut self.cells)
+    }
+
+    /// Iterates the orthogonal neighbors of a point that lie within the grid.
+    pub fn neighbors4(&self, point: Point) -> impl Iterator<Item = Point> + '_ {
+        point
+            .neighbors4()
+            .into_iter()
+            .filter(|&neighbor| self.contains(neighbor))
+    }
+
+    /// Iterates the neighbors of a point including diagonals that lie within the grid.
+    pub fn neighbors8(&self, point: Point) -> impl Iterator<Item = Point> + '_ {
+        point
+            .neighbors8()
+            .into_iter()
+            .filter(|&neighbor| self.contains(neighbor))
+    }
+
+    /// Returns a row of the grid.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the row is outside of the grid.
+    #[must_use]
+    pub fn row(&self, y: usize) -> &[T] {
+        assert!(y < self.height, "row {y} is outside of the grid");
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        (0..self.height).map(|y| self.row(y))
+    }
+
+    /// Iterates a column of the grid from top to bottom.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the column is outside of the grid.
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        assert!(x < self.width, "column {x} is outside of the grid");
+        self.cells.iter().skip(x).step_by(self.width)
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(|x| self.column(x))
+    }
+
+    /// Walks from `start` in steps of `step` until leaving the grid.
+    pub fn ray(&self, start: Point, step: Point) -> impl Iterator<Item = (Point, &T)> {
+        iter::successors(Some(start), move |&point| Some(point + step))
+            .map_while(|point| self.get(point).map(|cell| (point, cell)))
+    }
+
+    /// Iterates the diagonals running down and to the right, starting at the top right corner.
+    pub fn diagonals(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        let (width, height) = (self.width as isize, self.height as isize);
+        let starts = (0..width)
+            .rev()
+            .map(|x| Point::new(x, 0))
+            .chain((1..height).map(|y| Point::new(0, y)));
+        starts.map(|start| self.ray(start, Point::new(1, 1)).map(|(_, cell)| cell))
+    }
+
+    /// Iterates the diagonals running down and to the left, starting at the top left corner.
+    pub fn anti_diagonals(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        let (width, height) = (self.width as isize, self.height as isize);
+        let starts = (0..width)
+            .map(|x| Point::new(x, 0))
+            .chain((1..height).map(move |y| Point::new(width - 1, y)));
+        starts.map(|start| self.ray(start, Point::new(-1, 1)).map(|(_, cell)| cell))
+    }
+
+    /// Returns the first point in reading order whose cell matches a predicate.
+    pub fn find(&self, mut predicate: impl FnMut(&T) -> bool) -> Option<Point> {
+        self.iter()
+            .find(|(_, cell)| predicate(cell))
+            .map(|(point, _)| point)
+    }
+
+    /// Returns the first point in reading order that holds a value.
+    #[must_use]
+    pub fn position(&self, value: &T) -> Option<Point>
+    where
+        T: PartialEq,
+    {
+        self.find(|cell| cell == value)
+    }
+
+    /// Iterates the points whose cells match a predicate, in reading order.
+    pub fn positions<'a>(
+        &'a self,
+        mut predicate: impl FnMut(&T) -> bool + 'a,
+    ) -> impl Iterator<Item = Point> + 'a {
+        self.iter()
+            .filter(move |(_, cell)| predicate(cell))
+            .map(|(point, _)| point)
+    }
+
+    /// Creates a grid of the same size by mapping every cell.
+    #[must_use]
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+
+    /// Returns the grid mirrored along its main diagonal, turning rows into columns.
+    #[must_use]
+    pub fn transpose(&self) -> Self
+    where
+        T: Clone,
+    {
+        let cells = self.columns().flatten().cloned().collect();
+        Grid::from_vec(self.height, cells)
+    }
+
+    /// Returns the grid rotated clockwise by a quarter turn.
+    #[must_use]
+    pub fn rotate_right(&self) -> Self
+    where
+        T: Clone,
+    {
+        let cells = self
+            .columns()
+            .flat_map(|column| column.collect::<Vec<_>>().into_iter().rev())
+            .cloned()
+            .collect();
+        Grid::from_vec(self.height, cells)
+    }
+
+    /// Returns the grid rotated counterclockwise by a quarter turn.
+    #[must_use]
+    pub fn rotate_left(&self) -> Self
+    where
+        T: Clone,
+    {
+        let cells = (0..self.width)
+            .rev()
+            .flat_map(|x| self.column(x))
+            .cloned()
+            .collect();
+        Grid::from_vec(self.height, cells)
+    }
+}
+
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, point: Point) -> &T {
+        match self.index_of(point) {
+            Some(i) => &self.cells[i],
+            None => panic!(
+                "{point:?} is outside of the {}x{} grid",
+                self.width, self.height
+            ),
+        }
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, point: Point) -> &mut T {
+        match self.index_of(point) {
+            Some(i) => &mut self.cells[i],
+            None => panic!(
+                "{point:?} is outside of the {}x{} grid",
+                self.width, self.height
+            ),
+        }
+    }
+}
+
+impl FromStr for Grid<char> {
+    type Err = ParseGridError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Grid::parse_with(s, |c| c)
+    }
+}
+
+/// Renders the grid row by row, without a trailing newline.
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (y, row) in self.rows().enumerate() {
+            if y > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{cell}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{Direction, Grid, ParseGridError, Point};
+
+    fn grid(s: &str) -> Grid<char> {
+        s.parse().unwrap()
+    }
+
+    fn collect<'a>(lines: impl Iterator<Item = impl Iterator<Item = &'a char>>) -> Vec<String> {
+        lines.map(|line| line.collect()).collect()
+    }
+
+    #[test]
+    fn parses_and_renders_grids() {
+        let g = grid("abc\ndef\n\n");
+        assert_eq!((g.width(), g.height()), (3, 2));
+        assert_eq!(g[Point::new(2, 1)], 'f');
+        assert_eq!(g.get(Point::new(3, 0)), None);
+        assert_eq!(g.get(Point::new(-1, 0)), None);
+        assert_eq!(g.to_string(), "abc\ndef");
+
+        assert_eq!(
+            "ab\nc".parse::<Grid<char>>(),
+            Err(ParseGridError {
+                row: 1,
+                width: 1,
+                expected: 2
+            })
+        );
+        assert_eq!(grid("").width(), 0);
+
+        let digits = Grid::parse_with("12\n34", |c| c.to_digit(10).unwrap()).unwrap();
+        assert_eq!(digits.row(1), [3, 4]);
+    }
+
+    #[test]
+    fn iterates_neighbors() {
+        let g = grid("...\n...\n...");
+        assert_eq!(g.neighbors4(Point::new(1, 1)).count(), 4);
+        assert_eq!(g.neighbors8(Point::new(1, 1)).count(), 8);
+        assert_eq!(
+            g.neighbors4(Point::ORIGIN).collect::<Vec<_>>(),
+            [Point::new(1, 0), Point::new(0, 1)]
+        );
+        assert_eq!(g.neighbors8(Point::new(2, 2)).count(), 3);
+    }
+
+    #[test]
+    fn iterates_lines() {
+        let g = grid("abc\ndef");
+        assert_eq!(collect(g.columns()), ["ad", "be", "cf"]);
+        assert_eq!(collect(g.diagonals()), ["c", "bf", "ae", "d"]);
+        assert_eq!(collect(g.anti_diagonals()), ["a", "bd", "ce", "f"]);
+        assert_eq!(
+            g.ray(Point::new(2, 1), Direction::Left.offset())
+                .map(|(_, c)| c)
+                .collect::<String>(),
+            "fed"
+        );
+    }
+
+    #[test]
+    fn finds_cells() {
+        let g = grid(".#.\n#S#");
+        assert_eq!(g.position(&'S'), Some(Point::new(1, 1)));
+        assert_eq!(g.find(|&c| c == '#'), Some(Point::new(1, 0)));
+        assert_eq!(g.positions(|&c| c == '#').count(), 3);
+        assert_eq!(g.position(&'E'), None);
+    }
+
+    #[test]
+    fn transforms_grids() {
+        let g = grid("abc\ndef");
+        assert_eq!(g.transpose().to_string(), "ad\nbe\ncf");
+        assert_eq!(g.rotate_right().to_string(), "da\neb\nfc");
+        assert_eq!(g.rotate_left().to_string(), "cf\nbe\nad");
+        assert_eq!(g.rotate_right().rotate_left(), g);
+        assert_eq!(g.map(|c| c.to_ascii_uppercase()).to_string(), "ABC\nDEF");
+    }
+
+    #[test]
+    fn moves_points() {
+        let mut p = Point::new(2, 3);
+        p += Direction::Up;
+        assert_eq!(p, Point::new(2, 2));
+        assert_eq!(p + Point::new(1, 1) * 2, Point::new(4, 4));
+        assert_eq!(p.manhattan_distance(Point::ORIGIN), 4);
+        assert_eq!(Direction::Up.turn_left(), Direction::Left);
+        assert_eq!(Direction::Left.opposite(), Direction::Right);
+        assert_eq!(Direction::try_from('v'), Ok(Direction::Down));
+        assert!(Point::new(5, 0) < Point::new(0, 1));
+    }
+}
@@ -0,0 +1,288 @@
+//! Contiguous parts of a grid: horizontal [`Span`]s of cells, e.g. the numbers in a row,
+//! and connected [`Region`]s found by flood fill.
+
+use std::collections::BTreeSet;
+
+use super::{Grid, Point};
+
+/// Horizontal run of cells in a row, from column `start` up to but excluding column `end`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Span {
+    pub y: isize,
+    pub start: isize,
+    pub end: isize,
+}
+
+impl Span {
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.start.abs_diff(self.end)
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.start >= self.end
+    }
+
+    /// Iterates the points of the span from left to right.
+    pub fn points(&self) -> impl Iterator<Item = Point> {
+        let y = self.y;
+        (self.start..self.end).map(move |x| Point::new(x, y))
+    }
+
+    #[must_use]
+    pub fn contains(&self, point: Point) -> bool {
+        point.y == self.y && (self.start..self.end).contains(&point.x)
+    }
+
+    /// Returns whether a point touches the span, including diagonally. Points of the span itself do not.
+    #[must_use]
+    pub fn is_adjacent(&self, point: Point) -> bool {
+        point.y.abs_diff(self.y) <= 1
+            && (self.start - 1..=self.end).contains(&point.x)
+            && !self.contains(point)
+    }
+
+    /// Iterates the points around the span, including diagonals, in reading order.
+    /// The points may lie outside of the grid, see [`Grid::span_neighbors`].
+    pub fn neighbors(&self) -> impl Iterator<Item = Point> {
+        let span = *self;
+        (span.y - 1..=span.y + 1)
+            .flat_map(move |y| (span.start - 1..=span.end).map(move |x| Point::new(x, y)))
+            .filter(move |&point| !span.contains(point))
+    }
+}
+
+/// Connected set of cells.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Region {
+    points: BTreeSet<Point>,
+}
+
+impl Region {
+    /// Iterates the points of the region in reading order.
+    pub fn points(&self) -> impl Iterator<Item = Point> + '_ {
+        self.points.iter().copied()
+    }
+
+    #[must_use]
+    pub fn contains(&self, point: Point) -> bool {
+        self.points.contains(&point)
+    }
+
+    /// Number of cells in the region.
+    #[must_use]
+    pub fn area(&self) -> usize {
+        self.points.len()
+    }
+
+    /// Number of cell edges between the region and the cells around it.
+    #[must_use]
+    pub fn perimeter(&self) -> usize {
+        self.points()
+            .flat_map(Point::neighbors4)
+            .filter(|neighbor| !self.contains(*neighbor))
+            .count()
+    }
+
+    /// Returns whether a point outside of the region shares an edge with one of its cells.
+    #[must_use]
+    pub fn is_adjacent(&self, point: Point) -> bool {
+        !self.contains(point) && point.neighbors4().iter().any(|n| self.contains(*n))
+    }
+}
+
+impl<T> Grid<T> {
+    /// Finds the longest horizontal runs of cells matching a predicate, in reading order.
+    ///
+    /// ```
+    /// # use advent_of_code::utils::grid::Grid;
+    /// let grid: Grid<char> = "467..114\n...*....".parse().unwrap();
+    /// let numbers: Vec<String> = grid
+    ///     .spans(char::is_ascii_digit)
+    ///     .iter()
+    ///     .map(|span| grid.span_cells(span).iter().collect())
+    ///     .collect();
+    /// assert_eq!(numbers, ["467", "114"]);
+    /// ```
+    pub fn spans(&self, mut predicate: impl FnMut(&T) -> bool) -> Vec<Span> {
+        let mut spans = vec![];
+
+        for (y, row) in self.rows().enumerate() {
+            let mut start = None;
+
+            for x in 0..=row.len() {
+                match (start, row.get(x).is_some_and(&mut predicate)) {
+                    (None, true) => start = Some(x),
+                    (Some(begin), false) => {
+                        spans.push(Span {
+                            y: y as isize,
+                            start: begin as isize,
+                            end: x as isize,
+                        });
+                        start = None;
+                    }
+                    _ => {}
+                }
+            }
+        }
+
+        spans
+    }
+
+    /// Returns the cells of a span.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the span is not within the grid.
+    #[must_use]
+    pub fn span_cells(&self, span: &Span) -> &[T] {
+        let row = self.row(usize::try_from(span.y).expect("span is within the grid"));
+        let start = usize::try_from(span.start).expect("span is within the grid");
+        let end = usize::try_from(span.end).expect("span is within the grid");
+        &row[start..end]
+    }
+
+    /// Iterates the points around a span that lie within the grid, including diagonals.
+    pub fn span_neighbors(&self, span: &Span) -> impl Iterator<Item = Point> + '_ {
+        span.neighbors().filter(|&point| self.contains(point))
+    }
+
+    /// Finds the region of cells matching a predicate that are connected to `start` through their edges.
+    /// The region is empty if the cell at `start` does not match.
+    pub fn flood_fill(&self, start: Point, mut predicate: impl FnMut(&T) -> bool) -> Region {
+        let mut region = Region::default();
+        let mut stack = vec![start];
+
+        while let Some(point) = stack.pop() {
+            if region.contains(point) || !self.get(point).is_some_and(&mut predicate) {
+                continue;
+            }
+
+            region.points.insert(point);
+            stack.extend(self.neighbors4(point));
+        }
+
+        region
+    }
+
+    /// Labels connected regions of equal cells, e.g. the plots of a garden.
+    /// Every cell is set to the index of its region, regions are numbered in reading order of their first cell.
+    #[must_use]
+    pub fn label_regions(&self) -> Grid<usize>
+    where
+        T: PartialEq,
+    {
+        let mut labels = self.map(|_| usize::MAX);
+        let mut next = 0;
+
+        for point in self.points() {
+            if labels[point] != usize::MAX {
+                continue;
+            }
+
+            let value = &self[point];
+            for member in self.flood_fill(point, |cell| cell == value).points() {
+                labels[member] = next;
+            }
+            next += 1;
+        }
+
+        labels
+    }
+
+    /// Splits the grid into connected regions of equal cells, in reading order of their first cell.
+    #[must_use]
+    pub fn regions(&self) -> Vec<Region>
+    where
+        T: PartialEq,
+    {
+        let labels = self.label_regions();
+        let count = labels.cells.iter().max().map_or(0, |max| max + 1);
+        let mut regions = vec![Region::default(); count];
+
+        for (point, &label) in labels.iter() {
+            regions[label].points.insert(point);
+        }
+
+        regions
+    }
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::Span;
+    use crate::utils::grid::{Grid, Point};
+
+    fn grid(s: &str) -> Grid<char> {
+        s.parse().unwrap()
+    }
+
+    #[test]
+    fn finds_spans() {
+        let g = grid("12.3\n..45");
+        let spans = g.spans(char::is_ascii_digit);
+        assert_eq!(
+            spans,
+            [
+                Span {
+                    y: 0,
+                    start: 0,
+                    end: 2
+                },
+                Span {
+                    y: 0,
+                    start: 3,
+                    end: 4
+                },
+                Span {
+                    y: 1,
+                    start: 2,
+                    end: 4
+                },
+            ]
+        );
+        assert_eq!(g.span_cells(&spans[2]), ['4', '5']);
+        assert_eq!(spans[0].len(), 2);
+        assert_eq!(g.spans(|&c| c == '#'), []);
+    }
+
+    #[test]
+    fn finds_span_neighbors() {
+        let span = Span {
+            y: 1,
+            start: 1,
+            end: 3,
+        };
+        assert_eq!(span.neighbors().count(), 10);
+        assert!(span.is_adjacent(Point::new(0, 0)));
+        assert!(span.is_adjacent(Point::new(3, 2)));
+        assert!(!span.is_adjacent(Point::new(4, 1)));
+        assert!(!span.is_adjacent(Point::new(1, 1)));
+
+        let g = grid("123\n...");
+        let spans = g.spans(char::is_ascii_digit);
+        assert_eq!(g.span_neighbors(&spans[0]).count(), 3);
+    }
+
+    #[test]
+    fn fills_regions() {
+        let g = grid("AAB\nABB\nCCB");
+        let region = g.flood_fill(Point::ORIGIN, |&c| c == 'A');
+        assert_eq!(region.area(), 3);
+        assert_eq!(region.perimeter(), 8);
+        assert!(region.is_adjacent(Point::new(2, 0)));
+        assert!(!region.is_adjacent(Point::new(2, 2)));
+        assert_eq!(g.flood_fill(Point::ORIGIN, |&c| c == 'B').area(), 0);
+
+        let regions = g.regions();
+        assert_eq!(
+            regions
+                .iter()
+                .map(|r| (r.area(), r.perimeter()))
+                .collect::<Vec<_>>(),
+            [(3, 8), (4, 10), (2, 6)]
+        );
+        assert_eq!(g.label_regions().to_string(), "001\n011\n221");
+    }
+}
@@ -0,0 +1,493 @@
+//! Half-open integer intervals, sets of disjoint intervals and piecewise-linear [`RangeMap`]s,
+//! e.g. for puzzles that move whole ranges of numbers instead of single values.
+
+use std::fmt::{self, Display};
+use std::ops::Range;
+
+/// Interval of integers from `start` up to but excluding `end`. Intervals with `start >= end` are empty.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Interval {
+    pub start: i64,
+    pub end: i64,
+}
+
+impl Interval {
+    #[must_use]
+    pub const fn new(start: i64, end: i64) -> Self {
+        Self { start, end }
+    }
+
+    /// Creates the interval of `len` values beginning at `start`.
+    #[must_use]
+    pub const fn with_len(start: i64, len: i64) -> Self {
+        Self::new(start, start + len)
+    }
+
+    #[must_use]
+    pub fn len(&self) -> u64 {
+        if self.is_empty() {
+            0
+        } else {
+            self.end.abs_diff(self.start)
+        }
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.start >= self.end
+    }
+
+    #[must_use]
+    pub fn contains(&self, value: i64) -> bool {
+        self.start <= value && value < self.end
+    }
+
+    #[must_use]
+    pub fn overlaps(&self, other: &Self) -> bool {
+        self.intersection(other).is_some()
+    }
+
+    /// Returns the values in both intervals, `None` if they do not overlap.
+    #[must_use]
+    pub fn intersection(&self, other: &Self) -> Option<Self> {
+        let common = Self::new(self.start.max(other.start), self.end.min(other.end));
+        (!common.is_empty()).then_some(common)
+    }
+
+    /// Joins two intervals that overlap or touch, `None` if there is a gap between them.
+    #[must_use]
+    pub fn union(&self, other: &Self) -> Option<Self> {
+        if self.is_empty() {
+            Some(*other)
+        } else if other.is_empty() {
+            Some(*self)
+        } else if self.start <= other.end && other.start <= self.end {
+            Some(Self::new(
+                self.start.min(other.start),
+                self.end.max(other.end),
+            ))
+        } else {
+            None
+        }
+    }
+
+    /// Iterates the parts of the interval that are not in `other`, at most one on each side of it.
+    pub fn difference(&self, other: &Self) -> impl Iterator<Item = Self> {
+        let parts = match self.intersection(other) {
+            Some(common) => [
+                Self::new(self.start, common.start),
+                Self::new(common.end, self.end),
+            ],
+            None => [*self, Self::new(self.end, self.end)],
+        };
+        parts.into_iter().filter(|part| !part.is_empty())
+    }
+
+    /// Moves the interval by `offset`.
+    #[must_use]
+    pub fn shift(&self, offset: i64) -> Self {
+        Self::new(self.start + offset, self.end + offset)
+    }
+}
+
+impl From<Range<i64>> for Interval {
+    fn from(range: Range<i64>) -> Self {
+        Self::new(range.start, range.end)
+    }
+}
+
+impl Display for Interval {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "[{}, {})", self.start, self.end)
+    }
+}
+
+/// Set of integers stored as sorted, disjoint intervals. Overlapping and touching intervals are merged.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct IntervalSet {
+    intervals: Vec<Interval>,
+}
+
+impl IntervalSet {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds the values of an interval, merging it with the intervals it overlaps or touches.
+    pub fn insert(&mut self, interval: Interval) {
+        if interval.is_empty() {
+            return;
+        }
+
+        let from = self.intervals.partition_point(|i| i.end < interval.start);
+        let to = self.intervals.partition_point(|i| i.start <= interval.end);
+        let merged = self.intervals[from..to].iter().fold(interval, |merged, i| {
+            merged.union(i).expect("intervals touch")
+        });
+        self.intervals.splice(from..to, [merged]);
+    }
+
+    /// Iterates the intervals in ascending order.
+    pub fn iter(&self) -> impl Iterator<Item = Interval> + '_ {
+        self.intervals.iter().copied()
+    }
+
+    /// Number of disjoint intervals in the set.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.intervals.len()
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.intervals.is_empty()
+    }
+
+    /// Number of values in the set.
+    #[must_use]
+    pub fn size(&self) -> u64 {
+        self.intervals.iter().map(Interval::len).sum()
+    }
+
+    #[must_use]
+    pub fn contains(&self, value: i64) -> bool {
+        let index = self.intervals.partition_point(|i| i.end <= value);
+        self.intervals.get(index).is_some_and(|i| i.contains(value))
+    }
+
+    /// Smallest value in the set.
+    #[must_use]
+    pub fn min(&self) -> Option<i64> {
+        self.intervals.first().map(|i| i.start)
+    }
+
+    /// Largest value in the set.
+    #[must_use]
+    pub fn max(&self) -> Option<i64> {
+        self.intervals.last().map(|i| i.end - 1)
+    }
+
+    #[must_use]
+    pub fn union(&self, other: &Self) -> Self {
+        self.iter().chain(other.iter()).collect()
+    }
+
+    #[must_use]
+    pub fn intersection(&self, other: &Self) -> Self {
+        let (mut i, mut j) = (0, 0);
+        let mut intervals = vec![];
+
+        while let (Some(a), Some(b)) = (self.intervals.get(i), other.intervals.get(j)) {
+            intervals.extend(a.intersection(b));
+            if a.end < b.end {
+                i += 1;
+            } else {
+                j += 1;
+            }
+        }
+
+        Self { intervals }
+    }
+
+    #[must_use]
+    pub fn difference(&self, other: &Self) -> Self {
+        let mut intervals = vec![];
+
+        for interval in self.iter() {
+            let from = other.intervals.partition_point(|i| i.end <= interval.start);
+            let mut start = interval.start;
+
+            for removed in other.intervals[from..]
+                .iter()
+                .take_while(|i| i.start < interval.end)
+            {
+                if start < removed.start {
+                    intervals.push(Interval::new(start, removed.start));
+                }
+                start = removed.end;
+            }
+
+            if start < interval.end {
+                intervals.push(Interval::new(start, interval.end));
+            }
+        }
+
+        Self { intervals }
+    }
+}
+
+impl From<Interval> for IntervalSet {
+    fn from(interval: Interval) -> Self {
+        let mut set = Self::new();
+        set.insert(interval);
+        set
+    }
+}
+
+impl FromIterator<Interval> for IntervalSet {
+    fn from_iter<I: IntoIterator<Item = Interval>>(iter: I) -> Self {
+        let mut sorted: Vec<Interval> = iter.into_iter().filter(|i| !i.is_empty()).collect();
+        sorted.sort_unstable_by_key(|i| i.start);
+
+        let mut intervals: Vec<Interval> = Vec::with_capacity(sorted.len());
+        for interval in sorted {
+            match intervals.last_mut() {
+                Some(last) if interval.start <= last.end => last.end = last.end.max(interval.end),
+                _ => intervals.push(interval),
+            }
+        }
+
+        Self { intervals }
+    }
+}
+
+/// Piecewise-linear map that shifts the values of its intervals by an offset per interval
+/// and keeps all other values, e.g. the almanac maps of 2023 day 5.
+///
+/// ```
+/// # use advent_of_code::utils::interval::{Interval, IntervalSet, RangeMap};
+/// let seed_to_soil: RangeMap = [(Interval::new(50, 98), 2), (Interval::new(98, 100), -48)].into_iter().collect();
+/// assert_eq!(seed_to_soil.map(79), 81);
+/// assert_eq!(seed_to_soil.map(10), 10);
+///
+/// let soil = seed_to_soil.map_set(&IntervalSet::from(Interval::new(95, 101)));
+/// let parts: Vec<_> = soil.iter().collect();
+/// assert_eq!(parts, [Interval::new(50, 52), Interval::new(97, 101)]);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct RangeMap {
+    pieces: Vec<(Interval, i64)>,
+}
+
+impl RangeMap {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Shifts the values of `source` by `offset`. Values already mapped by an earlier piece keep their offset.
+    pub fn insert(&mut self, source: Interval, offset: i64) {
+        let mapped: IntervalSet = self.pieces.iter().map(|&(piece, _)| piece).collect();
+        let free = IntervalSet::from(source).difference(&mapped);
+        self.pieces.extend(free.iter().map(|part| (part, offset)));
+        self.pieces.sort_unstable_by_key(|(piece, _)| piece.start);
+    }
+
+    /// Iterates the intervals of the map with their offsets in ascending order.
+    pub fn pieces(&self) -> impl Iterator<Item = (Interval, i64)> + '_ {
+        self.pieces.iter().copied()
+    }
+
+    /// Returns the offset the map adds to a value.
+    #[must_use]
+    pub fn offset(&self, value: i64) -> i64 {
+        let index = self.pieces.partition_point(|(piece, _)| piece.end <= value);
+        self.pieces
+            .get(index)
+            .filter(|(piece, _)| piece.contains(value))
+            .map_or(0, |&(_, offset)| offset)
+    }
+
+    #[must_use]
+    pub fn map(&self, value: i64) -> i64 {
+        value + self.offset(value)
+    }
+
+    /// Maps all values of an interval, splitting it where the offset changes.
+    #[must_use]
+    pub fn map_interval(&self, interval: Interval) -> IntervalSet {
+        self.split(interval)
+            .into_iter()
+            .map(|(part, offset)| part.shift(offset))
+            .collect()
+    }
+
+    /// Maps all values of a set, splitting its intervals where the offset changes.
+    #[must_use]
+    pub fn map_set(&self, set: &IntervalSet) -> IntervalSet {
+        set.iter()
+            .flat_map(|interval| self.split(interval))
+            .map(|(part, offset)| part.shift(offset))
+            .collect()
+    }
+
+    /// Composes two maps into a single map that applies `self` and then `next`.
+    #[must_use]
+    pub fn then(&self, next: &Self) -> Self {
+        let mut pieces = vec![];
+
+        for &(source, offset) in &self.pieces {
+            for (part, next_offset) in next.split(source.shift(offset)) {
+                pieces.push((part.shift(-offset), offset + next_offset));
+            }
+        }
+
+        let mapped: IntervalSet = self.pieces.iter().map(|&(piece, _)| piece).collect();
+        for &(source, offset) in &next.pieces {
+            let unmapped = IntervalSet::from(source).difference(&mapped);
+            pieces.extend(unmapped.iter().map(|part| (part, offset)));
+        }
+
+        Self::from_pieces(pieces)
+    }
+
+    /// Cuts an interval into consecutive parts, each with the offset it is mapped by.
+    fn split(&self, interval: Interval) -> Vec<(Interval, i64)> {
+        if interval.is_empty() {
+            return vec![];
+        }
+
+        let from = self
+            .pieces
+            .partition_point(|(piece, _)| piece.end <= interval.start);
+        let mut start = interval.start;
+        let mut parts = vec![];
+
+        for &(piece, offset) in self.pieces[from..]
+            .iter()
+            .take_while(|(piece, _)| piece.start < interval.end)
+        {
+            if start < piece.start {
+                parts.push((Interval::new(start, piece.start), 0));
+            }
+            parts.push((
+                piece
+                    .intersection(&interval)
+                    .expect("piece overlaps interval"),
+                offset,
+            ));
+            start = piece.end;
+        }
+
+        if start < interval.end {
+            parts.push((Interval::new(start, interval.end), 0));
+        }
+
+        parts
+    }
+
+    /// Builds a map from disjoint pieces, dropping identity pieces and joining neighbors with the same offset.
+    fn from_pieces(mut unsorted: Vec<(Interval, i64)>) -> Self {
+        unsorted.retain(|&(piece, offset)| offset != 0 && !piece.is_empty());
+        unsorted.sort_unstable_by_key(|(piece, _)| piece.start);
+
+        let mut pieces: Vec<(Interval, i64)> = Vec::with_capacity(unsorted.len());
+        for (piece, offset) in unsorted {
+            match pieces.last_mut() {
+                Some((last, last_offset)) if last.end == piece.start && *last_offset == offset => {
+                    last.end = piece.end;
+                }
+                _ => pieces.push((piece, offset)),
+            }
+        }
+
+        Self { pieces }
+    }
+}
+
+impl FromIterator<(Interval, i64)> for RangeMap {
+    fn from_iter<I: IntoIterator<Item = (Interval, i64)>>(iter: I) -> Self {
+        let mut map = Self::new();
+        for (source, offset) in iter {
+            map.insert(source, offset);
+        }
+        map
+    }
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{Interval, IntervalSet, RangeMap};
+
+    fn set(intervals: &[(i64, i64)]) -> IntervalSet {
+        intervals
+            .iter()
+            .map(|&(start, end)| Interval::new(start, end))
+            .collect()
+    }
+
+    #[test]
+    fn combines_intervals() {
+        let a = Interval::new(0, 10);
+        let b = Interval::new(5, 15);
+        assert_eq!(a.len(), 10);
+        assert_eq!(a.intersection(&b), Some(Interval::new(5, 10)));
+        assert_eq!(a.intersection(&Interval::new(10, 12)), None);
+        assert_eq!(a.union(&b), Some(Interval::new(0, 15)));
+        assert_eq!(a.union(&Interval::new(10, 12)), Some(Interval::new(0, 12)));
+        assert_eq!(a.union(&Interval::new(11, 12)), None);
+        assert_eq!(
+            a.difference(&Interval::new(3, 5)).collect::<Vec<_>>(),
+            [Interval::new(0, 3), Interval::new(5, 10)]
+        );
+        assert_eq!(a.difference(&b).collect::<Vec<_>>(), [Interval::new(0, 5)]);
+        assert_eq!(a.difference(&Interval::new(-5, 20)).count(), 0);
+        assert_eq!(Interval::new(3, 1).len(), 0);
+        assert_eq!(Interval::from(1..4).to_string(), "[1, 4)");
+    }
+
+    #[test]
+    fn merges_sets() {
+        let mut merged = set(&[(5, 8), (0, 2), (1, 3), (3, 4)]);
+        assert_eq!(merged, set(&[(0, 4), (5, 8)]));
+        assert_eq!(merged.size(), 7);
+        assert!(merged.contains(7));
+        assert!(!merged.contains(4));
+        assert_eq!((merged.min(), merged.max()), (Some(0), Some(7)));
+
+        merged.insert(Interval::new(4, 5));
+        assert_eq!(merged, set(&[(0, 8)]));
+        merged.insert(Interval::new(10, 12));
+        assert_eq!(merged.len(), 2);
+    }
+
+    #[test]
+    fn combines_sets() {
+        let a = set(&[(0, 5), (10, 15)]);
+        let b = set(&[(3, 12), (14, 20)]);
+        assert_eq!(a.union(&b), set(&[(0, 20)]));
+        assert_eq!(a.intersection(&b), set(&[(3, 5), (10, 12), (14, 15)]));
+        assert_eq!(a.difference(&b), set(&[(0, 3), (12, 14)]));
+        assert_eq!(b.difference(&a), set(&[(5, 10), (15, 20)]));
+    }
+
+    #[test]
+    fn maps_ranges() {
+        let map: RangeMap = [(Interval::new(0, 10), 100), (Interval::new(5, 20), -5)]
+            .into_iter()
+            .collect();
+        assert_eq!(map.map(7), 107);
+        assert_eq!(map.map(12), 7);
+        assert_eq!(map.map(25), 25);
+        assert_eq!(
+            map.map_interval(Interval::new(8, 22)),
+            set(&[(5, 15), (20, 22), (108, 110)])
+        );
+    }
+
+    #[test]
+    fn composes_maps() {
+        let first: RangeMap = [(Interval::new(0, 10), 10)].into_iter().collect();
+        let second: RangeMap = [(Interval::new(5, 15), 100)].into_iter().collect();
+        let composed = first.then(&second);
+
+        for value in -5..30 {
+            assert_eq!(composed.map(value), second.map(first.map(value)), "{value}");
+        }
+        assert_eq!(
+            composed.pieces().collect::<Vec<_>>(),
+            [
+                (Interval::new(0, 5), 110),
+                (Interval::new(5, 10), 10),
+                (Interval::new(10, 15), 100),
+            ]
+        );
+
+        let input = set(&[(-5, 30)]);
+        assert_eq!(
+            composed.map_set(&input),
+            second.map_set(&first.map_set(&input))
+        );
+    }
+}
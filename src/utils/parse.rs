@@ -0,0 +1,429 @@
+//! Fallible parsing of integers from puzzle input.
+//! Unlike [`str::parse`], errors carry the position of the offending byte, which makes bad input easy to find.
+
+use std::error::Error;
+use std::fmt::{self, Display};
+use std::marker::PhantomData;
+
+/// Integer types that [`parse_int`] can parse into.
+pub trait Integer: Copy {
+    /// Whether the type can hold negative values.
+    const SIGNED: bool;
+    /// Number of digits that always fit into the type, e.g. `9` for `u32`.
+    /// Numbers up to this length are parsed without overflow checks.
+    const SAFE_DIGITS: usize;
+    const ZERO: Self;
+
+    /// Returns `self * 10 + digit`, or [`None`] on overflow.
+    fn push_digit(self, digit: u8) -> Option<Self>;
+    /// Returns `self * 10 - digit`, or [`None`] on overflow.
+    /// Negative numbers are accumulated downwards so that e.g. `i32::MIN` can be parsed.
+    fn push_negative_digit(self, digit: u8) -> Option<Self>;
+    /// Returns `self * 10 + digit`, wrapping on overflow.
+    fn push_digit_unchecked(self, digit: u8) -> Self;
+    /// Returns `self * 10 - digit`, wrapping on overflow.
+    fn push_negative_digit_unchecked(self, digit: u8) -> Self;
+}
+
+macro_rules! impl_integer {
+    ($signed:expr; $($t:ty),*) => {
+        $(
+            impl Integer for $t {
+                const SIGNED: bool = $signed;
+                const SAFE_DIGITS: usize = <$t>::MAX.ilog10() as usize;
+                const ZERO: Self = 0;
+
+                fn push_digit(self, digit: u8) -> Option<Self> {
+                    self.checked_mul(10)?.checked_add(digit as Self)
+                }
+
+                fn push_negative_digit(self, digit: u8) -> Option<Self> {
+                    self.checked_mul(10)?.checked_sub(digit as Self)
+                }
+
+                fn push_digit_unchecked(self, digit: u8) -> Self {
+                    self.wrapping_mul(10).wrapping_add(digit as Self)
+                }
+
+                fn push_negative_digit_unchecked(self, digit: u8) -> Self {
+                    self.wrapping_mul(10).wrapping_sub(digit as Self)
+                }
+            }
+        )*
+    };
+}
+
+impl_integer!(false; u8, u16, u32, u64, u128, usize);
+impl_integer!(true; i8, i16, i32, i64, i128, isize);
+
+/// Reason why an integer could not be parsed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ParseIntErrorKind {
+    /// There are no digits, e.g. in `""` or `"-"`.
+    Empty,
+    /// A byte is not a digit, or a minus sign was found while parsing an unsigned type.
+    InvalidDigit,
+    /// The number does not fit into the target type.
+    Overflow,
+}
+
+/// Error of [`parse_int`], with the byte position at which parsing failed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ParseIntError {
+    kind: ParseIntErrorKind,
+    position: usize,
+}
+
+impl ParseIntError {
+    #[must_use]
+    pub fn kind(&self) -> ParseIntErrorKind {
+        self.kind
+    }
+
+    /// Byte position in the input at which parsing failed.
+    /// For [`ParseIntErrorKind::Overflow`], this is the first digit that did not fit.
+    #[must_use]
+    pub fn position(&self) -> usize {
+        self.position
+    }
+}
+
+impl Display for ParseIntError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.kind {
+            ParseIntErrorKind::Empty => {
+                write!(f, "no digits to parse at position {}", self.position)
+            }
+            ParseIntErrorKind::InvalidDigit => {
+                write!(f, "invalid digit at position {}", self.position)
+            }
+            ParseIntErrorKind::Overflow => write!(
+                f,
+                "number does not fit into the target type at position {}",
+                self.position
+            ),
+        }
+    }
+}
+
+impl Error for ParseIntError {}
+
+/// Parses an integer with an optional leading `+` or `-` sign.
+///
+/// ```
+/// # use advent_of_code::utils::{parse_int, ParseIntErrorKind};
+/// assert_eq!(parse_int::<i64>("-42"), Ok(-42));
+/// assert_eq!(parse_int::<u8>("256").unwrap_err().kind(), ParseIntErrorKind::Overflow);
+/// assert_eq!(parse_int::<u32>("12a").unwrap_err().position(), 2);
+/// ```
+pub fn parse_int<T: Integer>(s: &str) -> Result<T, ParseIntError> {
+    parse_int_bytes(s.as_bytes())
+}
+
+/// Parses an integer from bytes, see [`parse_int`].
+/// Useful when working on the bytes of the input, e.g. with [`str::as_bytes`].
+pub fn parse_int_bytes<T: Integer>(bytes: &[u8]) -> Result<T, ParseIntError> {
+    let error = |kind, position| Err(ParseIntError { kind, position });
+
+    let (negative, start) = match bytes.first() {
+        Some(b'-') if !T::SIGNED => return error(ParseIntErrorKind::InvalidDigit, 0),
+        Some(b'-') => (true, 1),
+        Some(b'+') => (false, 1),
+        _ => (false, 0),
+    };
+
+    let digits = &bytes[start..];
+
+    if digits.is_empty() {
+        return error(ParseIntErrorKind::Empty, bytes.len());
+    }
+
+    // short numbers cannot overflow, so they skip the checks.
+    let checked = digits.len() > T::SAFE_DIGITS;
+    let mut value = T::ZERO;
+
+    for (i, &byte) in digits.iter().enumerate() {
+        let digit = byte.wrapping_sub(b'0');
+        if digit > 9 {
+            return error(ParseIntErrorKind::InvalidDigit, start + i);
+        }
+
+        value = match (checked, negative) {
+            (false, false) => value.push_digit_unchecked(digit),
+            (false, true) => value.push_negative_digit_unchecked(digit),
+            (true, false) => match value.push_digit(digit) {
+                Some(value) => value,
+                None => return error(ParseIntErrorKind::Overflow, start + i),
+            },
+            (true, true) => match value.push_negative_digit(digit) {
+                Some(value) => value,
+                None => return error(ParseIntErrorKind::Overflow, start + i),
+            },
+        };
+    }
+
+    Ok(value)
+}
+
+/// Iterator over the integers found in a text, see [`numbers`].
+#[derive(Debug, Clone)]
+pub struct Numbers<'a, T> {
+    bytes: &'a [u8],
+    position: usize,
+    integer: PhantomData<T>,
+}
+
+/// Returns an iterator over all integers in a text, skipping everything in between.
+///
+/// A `-` directly in front of a number is read as a minus sign for signed types, unless it
+/// follows a letter or digit, so ranges like `3-7` and names like `x-5` yield positive numbers.
+/// Unsigned types ignore minus signs.
+///
+/// ```
+/// # use advent_of_code::utils::numbers;
+/// let values: Vec<i32> = numbers("Time: 7 15, offset -30, range 3-7").collect();
+/// assert_eq!(values, [7, 15, -30, 3, 7]);
+/// ```
+///
+/// # Panics
+///
+/// The iterator panics if a number does not fit into `T`.
+#[must_use]
+pub fn numbers<T: Integer>(s: &str) -> Numbers<'_, T> {
+    Numbers {
+        bytes: s.as_bytes(),
+        position: 0,
+        integer: PhantomData,
+    }
+}
+
+impl<T: Integer> Numbers<'_, T> {
+    /// Collects the numbers into an array. Returns [`None`] unless there are exactly `N` numbers.
+    ///
+    /// ```
+    /// # use advent_of_code::utils::numbers;
+    /// assert_eq!(numbers::<u64>("50 98 2").collect_array(), Some([50, 98, 2]));
+    /// assert_eq!(numbers::<u64>("50 98").collect_array::<3>(), None);
+    /// ```
+    pub fn collect_array<const N: usize>(mut self) -> Option<[T; N]> {
+        let mut array = [T::ZERO; N];
+
+        for value in &mut array {
+            *value = self.next()?;
+        }
+
+        (!has_digits(self.bytes, self.position)).then_some(array)
+    }
+}
+
+impl<T: Integer> Iterator for Numbers<'_, T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        next_number(self.bytes, &mut self.position)
+    }
+}
+
+/// Returns whether there is another number in `bytes` at or after `position`, without parsing it.
+/// Trailing numbers only decide whether a fixed count of numbers matched, so they must not overflow.
+fn has_digits(bytes: &[u8], position: usize) -> bool {
+    bytes[position..].iter().any(u8::is_ascii_digit)
+}
+
+/// Returns the next number in `bytes` at or after `position` and moves `position` past it.
+fn next_number<T: Integer>(bytes: &[u8], position: &mut usize) -> Option<T> {
+    let start = *position + bytes[*position..].iter().position(u8::is_ascii_digit)?;
+    let end = bytes[start..]
+        .iter()
+        .position(|b| !b.is_ascii_digit())
+        .map_or(bytes.len(), |len| start + len);
+
+    *position = end;
+
+    let negative = T::SIGNED
+        && start > 0
+        && bytes[start - 1] == b'-'
+        && (start < 2 || !bytes[start - 2].is_ascii_alphanumeric());
+    let start = if negative { start - 1 } else { start };
+
+    match parse_int_bytes(&bytes[start..end]) {
+        Ok(value) => Some(value),
+        Err(e) => panic!(
+            "{}",
+            ParseIntError {
+                kind: e.kind,
+                position: start + e.position,
+            }
+        ),
+    }
+}
+
+/// Types that [`parse_numbers`] can collect numbers into: arrays and tuples of up to six integers.
+pub trait FromNumbers: Sized {
+    /// Reads the numbers of a text, [`None`] unless they match the number of elements.
+    fn from_numbers(s: &str) -> Option<Self>;
+}
+
+impl<T: Integer, const N: usize> FromNumbers for [T; N] {
+    fn from_numbers(s: &str) -> Option<Self> {
+        numbers(s).collect_array()
+    }
+}
+
+macro_rules! impl_from_numbers {
+    ($($t:ident),+) => {
+        impl<$($t: Integer),+> FromNumbers for ($($t,)+) {
+            fn from_numbers(s: &str) -> Option<Self> {
+                let (bytes, mut position) = (s.as_bytes(), 0);
+                let numbers = ($(next_number::<$t>(bytes, &mut position)?,)+);
+                (!has_digits(bytes, position)).then_some(numbers)
+            }
+        }
+    };
+}
+
+impl_from_numbers!(A);
+impl_from_numbers!(A, B);
+impl_from_numbers!(A, B, C);
+impl_from_numbers!(A, B, C, D);
+impl_from_numbers!(A, B, C, D, E);
+impl_from_numbers!(A, B, C, D, E, F);
+
+/// Reads all numbers of a text into an array or tuple, see [`numbers`].
+/// Returns [`None`] unless the text has exactly as many numbers as the result has elements.
+///
+/// ```
+/// # use advent_of_code::utils::parse_numbers;
+/// let (id, x, y): (u32, i64, i64) = parse_numbers("#3 @ -1,4").unwrap();
+/// assert_eq!((id, x, y), (3, -1, 4));
+/// ```
+///
+/// # Panics
+///
+/// Panics if one of the numbers read into the result does not fit into its element type.
+/// Extra numbers are not parsed, so they only make the result [`None`].
+#[must_use]
+pub fn parse_numbers<F: FromNumbers>(s: &str) -> Option<F> {
+    F::from_numbers(s)
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{
+        numbers, parse_int, parse_int_bytes, parse_numbers, ParseIntError, ParseIntErrorKind,
+    };
+
+    fn error(kind: ParseIntErrorKind, position: usize) -> ParseIntError {
+        ParseIntError { kind, position }
+    }
+
+    #[test]
+    fn parses_integers() {
+        assert_eq!(parse_int::<u32>("0"), Ok(0));
+        assert_eq!(parse_int::<u32>("+17"), Ok(17));
+        assert_eq!(parse_int::<i32>("-17"), Ok(-17));
+        assert_eq!(parse_int::<u64>("4294967296"), Ok(4_294_967_296));
+        assert_eq!(parse_int::<u64>("000000000000000000000042"), Ok(42));
+        assert_eq!(parse_int_bytes::<usize>(b"123"), Ok(123));
+    }
+
+    #[test]
+    fn parses_limits() {
+        assert_eq!(parse_int::<u32>("4294967295"), Ok(u32::MAX));
+        assert_eq!(parse_int::<i8>("-128"), Ok(i8::MIN));
+        assert_eq!(parse_int::<i8>("127"), Ok(i8::MAX));
+        assert_eq!(parse_int::<i64>("-9223372036854775808"), Ok(i64::MIN));
+        assert_eq!(parse_int::<u128>(&u128::MAX.to_string()), Ok(u128::MAX));
+    }
+
+    #[test]
+    fn detects_overflow() {
+        assert_eq!(
+            parse_int::<u32>("4294967296"),
+            Err(error(ParseIntErrorKind::Overflow, 9))
+        );
+        assert_eq!(
+            parse_int::<i8>("-129"),
+            Err(error(ParseIntErrorKind::Overflow, 3))
+        );
+        assert_eq!(
+            parse_int::<u8>("1000"),
+            Err(error(ParseIntErrorKind::Overflow, 3))
+        );
+    }
+
+    #[test]
+    fn reports_invalid_input() {
+        assert_eq!(
+            parse_int::<u32>(""),
+            Err(error(ParseIntErrorKind::Empty, 0))
+        );
+        assert_eq!(
+            parse_int::<i32>("-"),
+            Err(error(ParseIntErrorKind::Empty, 1))
+        );
+        assert_eq!(
+            parse_int::<u32>("-1"),
+            Err(error(ParseIntErrorKind::InvalidDigit, 0))
+        );
+        assert_eq!(
+            parse_int::<u32>("12 3"),
+            Err(error(ParseIntErrorKind::InvalidDigit, 2))
+        );
+        assert_eq!(
+            parse_int::<i64>("--1"),
+            Err(error(ParseIntErrorKind::InvalidDigit, 1))
+        );
+        assert_eq!(
+            parse_int::<u32>("1x").unwrap_err().to_string(),
+            "invalid digit at position 1"
+        );
+    }
+
+    #[test]
+    fn finds_numbers() {
+        let line = "Game 12: 3 blue, -4 red; x-5 1-2 --6 +7";
+        assert_eq!(
+            numbers::<i32>(line).collect::<Vec<_>>(),
+            [12, 3, -4, 5, 1, 2, -6, 7]
+        );
+        assert_eq!(
+            numbers::<u32>(line).collect::<Vec<_>>(),
+            [12, 3, 4, 5, 1, 2, 6, 7]
+        );
+        assert_eq!(numbers::<u8>("no numbers - here").next(), None);
+        assert_eq!(numbers::<i64>("-").next(), None);
+        assert_eq!(
+            numbers::<u64>("seeds: 79 14\n55 13").collect::<Vec<_>>(),
+            [79, 14, 55, 13]
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "number does not fit into the target type at position 11")]
+    fn panics_on_overflowing_numbers() {
+        numbers::<u8>("a 1 b 2, 300").for_each(drop);
+    }
+
+    #[test]
+    fn collects_numbers() {
+        assert_eq!(numbers::<u32>("1 2 3").collect_array(), Some([1, 2, 3]));
+        assert_eq!(numbers::<u32>("1 2 3").collect_array::<2>(), None);
+        assert_eq!(numbers::<u32>("").collect_array::<0>(), Some([]));
+
+        assert_eq!(
+            parse_numbers::<(u8, i16, u64)>("move 1 by -2 to 3"),
+            Some((1, -2, 3))
+        );
+        assert_eq!(parse_numbers::<(u8, u8)>("1 2 3"), None);
+        assert_eq!(parse_numbers::<(u64, u64)>("seed 1 2 and id 999999"), None);
+        assert_eq!(
+            parse_numbers::<(u8, u8)>("1 2 99999999999999999999999999999999999999999"),
+            None
+        );
+        assert_eq!(parse_numbers::<[u8; 2]>("1 2 300"), None);
+        assert_eq!(numbers::<u8>("1 2 300").collect_array::<2>(), None);
+        assert_eq!(parse_numbers::<(u8, u8)>("1"), None);
+        assert_eq!(parse_numbers::<[i32; 2]>("p=-3,4"), Some([-3, 4]));
+    }
+}